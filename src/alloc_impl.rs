@@ -0,0 +1,36 @@
+use alloc::{borrow::Cow, string::String};
+use core::{
+    convert::{From, TryFrom},
+    result::Result,
+};
+
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+impl<const N: usize> FixedString<N> {
+    /// Returns the content as a borrowed `Cow<str>`
+    pub fn into_cow(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.as_str())
+    }
+}
+
+impl<const N: usize> From<FixedString<N>> for String {
+    fn from(fixed_string: FixedString<N>) -> Self {
+        String::from(fixed_string.as_str())
+    }
+}
+
+impl<'a, const N: usize> From<&'a FixedString<N>> for Cow<'a, str> {
+    fn from(fixed_string: &'a FixedString<N>) -> Self {
+        fixed_string.into_cow()
+    }
+}
+
+/// Create a `FixedString` from a `String`, failing if it doesn't fit the
+/// capacity
+impl<const N: usize> TryFrom<String> for FixedString<N> {
+    type Error = FixedStringError;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        Self::new_with(&string)
+    }
+}
@@ -0,0 +1,38 @@
+use core::{
+    result::Result::{Err, Ok},
+    str,
+};
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{FixedString, LengthType, Utf8};
+
+impl<'a, const N: usize, L: LengthType> Arbitrary<'a> for FixedString<N, Utf8, L> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let length = u.arbitrary_len::<u8>()?.min(N);
+        from_valid_prefix(u.bytes(length)?)
+    }
+
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        let length = u.len().min(N);
+        from_valid_prefix(u.bytes(length)?)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, Some(N))
+    }
+}
+
+/// Creates a `FixedString` from the longest valid UTF-8 prefix of `bytes`,
+/// which fit in the capacity
+fn from_valid_prefix<const N: usize, L: LengthType>(
+    bytes: &[u8],
+) -> Result<FixedString<N, Utf8, L>> {
+    let string = match str::from_utf8(bytes) {
+        Ok(string) => string,
+        Err(err) => unsafe {
+            str::from_utf8_unchecked(bytes.get(..err.valid_up_to()).unwrap_or_default())
+        },
+    };
+    FixedString::new_with(string).map_err(|_| arbitrary::Error::IncorrectFormat)
+}
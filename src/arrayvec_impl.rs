@@ -0,0 +1,20 @@
+use core::convert::From;
+
+use arrayvec::ArrayString;
+
+use crate::{FixedString, FixedStringView};
+
+impl<const N: usize> From<FixedString<N>> for ArrayString<N> {
+    fn from(fixed_string: FixedString<N>) -> Self {
+        let mut string = ArrayString::new();
+        // Both have a capacity of `N`, so this can't overflow
+        let _ = string.try_push_str(fixed_string.as_str());
+        string
+    }
+}
+
+impl<const N: usize> From<ArrayString<N>> for FixedString<N> {
+    fn from(string: ArrayString<N>) -> Self {
+        Self::from_valid_bytes(string.as_bytes())
+    }
+}
@@ -0,0 +1,162 @@
+use core::{
+    option::Option,
+    result::Result::{self, Err, Ok},
+    str,
+};
+
+use crate::{FixedString, FixedStringError, LengthType, TextEncoding, Utf8};
+
+const RFC4648_DIGITS: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const CROCKFORD_DIGITS: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Alphabet of Base32 output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base32Alphabet {
+    /// The alphabet of RFC 4648, padded with `=` to a multiple of 8
+    /// characters
+    Rfc4648,
+    /// Crockford's alphabet without padding, which avoids `I`, `L`, `O` and
+    /// `U`. Decoding reads `I` and `L` as `1`, `O` as `0` and skips `-`, for
+    /// codes typed in by hand.
+    Crockford,
+}
+
+impl Base32Alphabet {
+    fn digits(self) -> &'static [u8; 32] {
+        match self {
+            Base32Alphabet::Rfc4648 => RFC4648_DIGITS,
+            Base32Alphabet::Crockford => CROCKFORD_DIGITS,
+        }
+    }
+
+    /// Returns the number of characters `length` bytes are encoded as
+    fn encoded_len(self, length: usize) -> usize {
+        match self {
+            Base32Alphabet::Rfc4648 => length.div_ceil(5).saturating_mul(8),
+            Base32Alphabet::Crockford => length.saturating_mul(8).div_ceil(5),
+        }
+    }
+
+    /// Returns the value of a digit in either case
+    fn value(self, digit: u8) -> Option<u8> {
+        let digit = digit.to_ascii_uppercase();
+        let digit = match (self, digit) {
+            (Base32Alphabet::Crockford, b'I' | b'L') => b'1',
+            (Base32Alphabet::Crockford, b'O') => b'0',
+            _ => digit,
+        };
+        self.digits()
+            .iter()
+            .position(|candidate| *candidate == digit)
+            .map(|value| value as u8)
+    }
+}
+
+impl<const N: usize, E: TextEncoding, L: LengthType> FixedString<N, E, L> {
+    /// Creates a new `FixedString` with `bytes` encoded as Base32
+    pub fn from_base32(bytes: &[u8], alphabet: Base32Alphabet) -> Result<Self, FixedStringError> {
+        let mut res = Self::new();
+        res.push_base32(bytes, alphabet)?;
+        Ok(res)
+    }
+
+    /// Appends `bytes` encoded as Base32, if they fit
+    pub fn push_base32(
+        &mut self,
+        bytes: &[u8],
+        alphabet: Base32Alphabet,
+    ) -> Result<(), FixedStringError> {
+        self.grown_length(alphabet.encoded_len(bytes.len()))?;
+
+        let digits = alphabet.digits();
+        for chunk in bytes.chunks(5) {
+            let mut group = [0; 5];
+            for (byte, source) in group.iter_mut().zip(chunk) {
+                *byte = *source;
+            }
+            let value = group
+                .iter()
+                .fold(0u64, |value, byte| (value << 8) | u64::from(*byte));
+
+            let mut encoded = [b'='; 8];
+            let used = chunk.len().saturating_mul(8).div_ceil(5);
+            for (i, digit) in encoded.iter_mut().take(used).enumerate() {
+                let shift = 35usize.saturating_sub(i.saturating_mul(5));
+                *digit = digits
+                    .get(((value >> shift) & 0x1f) as usize)
+                    .copied()
+                    .unwrap_or_default();
+            }
+            let end = match alphabet {
+                Base32Alphabet::Rfc4648 => encoded.len(),
+                Base32Alphabet::Crockford => used,
+            };
+            self.push(unsafe { str::from_utf8_unchecked(encoded.get(..end).unwrap_or_default()) })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Decodes Base32 content of either case into `out`, returning the number
+    /// of bytes written
+    pub fn decode_base32_into(
+        &self,
+        out: &mut [u8],
+        alphabet: Base32Alphabet,
+    ) -> Result<usize, FixedStringError> {
+        let mut digits = self.as_units();
+        if alphabet == Base32Alphabet::Rfc4648 {
+            if !digits.len().is_multiple_of(8) {
+                return Err(FixedStringError::InvalidBase32 {
+                    index: digits.len(),
+                });
+            }
+            while let [rest @ .., b'='] = digits {
+                digits = rest;
+            }
+        }
+        let is_separator = |digit: &u8| alphabet == Base32Alphabet::Crockford && *digit == b'-';
+
+        // Only these numbers of trailing digits encode whole bytes
+        let count = digits.iter().filter(|digit| !is_separator(digit)).count();
+        if matches!(count % 8, 1 | 3 | 6) {
+            return Err(FixedStringError::InvalidBase32 {
+                index: digits.len(),
+            });
+        }
+
+        let decoded_len = count.saturating_mul(5) / 8;
+        if decoded_len > out.len() {
+            return Err(FixedStringError::Overflow {
+                requested: decoded_len,
+                available: out.len(),
+            });
+        }
+
+        let mut written = 0;
+        let mut buffer = 0u16;
+        let mut bits = 0u32;
+        for (index, digit) in digits.iter().enumerate() {
+            if is_separator(digit) {
+                continue;
+            }
+            let value = alphabet
+                .value(*digit)
+                .ok_or(FixedStringError::InvalidBase32 { index })?;
+            buffer = (buffer << 5) | u16::from(value);
+            bits = bits.saturating_add(5);
+            if bits >= 8 {
+                bits = bits.saturating_sub(8);
+                if let Some(byte) = out.get_mut(written) {
+                    *byte = (buffer >> bits) as u8;
+                }
+                buffer &= (1u16 << bits).wrapping_sub(1);
+                written = written.saturating_add(1);
+            }
+        }
+
+        Ok(written)
+    }
+}
@@ -0,0 +1,144 @@
+use core::{
+    default::Default,
+    ffi::{CStr, c_char},
+    fmt,
+    result::Result::{self, Err, Ok},
+    write,
+};
+
+use crate::{CHARACTER_NONE, FixedString, FixedStringError, FixedStringView, write_escaped};
+
+/// A fixed string that always keeps a trailing NUL after its content and
+/// never contains interior NULs, so it can be handed directly over FFI
+/// boundaries. One byte of the capacity `N` is reserved for the NUL.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CFixedString<const N: usize> {
+    inner: FixedString<N>,
+}
+
+impl<const N: usize> CFixedString<N> {
+    /// Creates a new empty `CFixedString`.
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "a CFixedString needs room for the NUL terminator") };
+
+        // The first unit of a new `FixedString` is already initialized to NUL
+        Self {
+            inner: FixedString::new(),
+        }
+    }
+
+    /// Creates a new `CFixedString` with an assigned value
+    pub fn new_with(string: &str) -> Result<Self, FixedStringError> {
+        let mut res = Self::new();
+        res.push(string)?;
+        Ok(res)
+    }
+
+    /// Creates a new `CFixedString` from a C string
+    pub fn from_c_str(c_str: &CStr) -> Result<Self, FixedStringError> {
+        match c_str.to_str() {
+            Ok(string) => Self::new_with(string),
+            Err(_) => Err(FixedStringError::InvalidUtf8),
+        }
+    }
+
+    /// Returns a `&str` representation of the content, without the NUL
+    pub fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns the content as a NUL-terminated `&CStr`
+    pub fn as_c_str(&self) -> &CStr {
+        let bytes = self
+            .inner
+            .raw()
+            .get(..=self.inner.length)
+            .unwrap_or(&[CHARACTER_NONE]);
+        unsafe { CStr::from_bytes_with_nul_unchecked(bytes) }
+    }
+
+    /// Returns a pointer to the NUL-terminated content
+    pub fn as_ptr(&self) -> *const c_char {
+        self.inner.as_ptr() as *const c_char
+    }
+
+    /// Returns the current length, without the NUL
+    pub fn length(&self) -> usize {
+        self.inner.length
+    }
+
+    /// Returns the number of characters that fit, without the NUL
+    pub fn capacity(&self) -> usize {
+        N.saturating_sub(1)
+    }
+
+    /// Returns `true` if the `CFixedString` is full.
+    pub fn is_full(&self) -> bool {
+        self.inner.length == self.capacity()
+    }
+
+    /// Clear the content of a `CFixedString`
+    pub fn clear(&mut self) {
+        self.inner.length = 0;
+        let _ = self.inner.write_units(0, &[CHARACTER_NONE]);
+    }
+
+    /// Appends a string slice, failing if it contains a NUL or doesn't fit
+    pub fn push(&mut self, string: &str) -> Result<(), FixedStringError> {
+        if let Some(index) = string.bytes().position(|byte| byte == CHARACTER_NONE) {
+            return Err(FixedStringError::InteriorNul {
+                index: self.inner.length.saturating_add(index),
+            });
+        }
+
+        let available = self.capacity().saturating_sub(self.inner.length);
+        if string.len() > available {
+            return Err(FixedStringError::Overflow {
+                requested: string.len(),
+                available,
+            });
+        }
+
+        self.inner.push(string)?;
+        self.inner
+            .write_units(self.inner.length, &[CHARACTER_NONE])?;
+
+        Ok(())
+    }
+
+    /// Appends a character, failing if it's a NUL or doesn't fit
+    pub fn push_char(&mut self, character: char) -> Result<(), FixedStringError> {
+        let mut encoded = [0; 4];
+        self.push(character.encode_utf8(&mut encoded))
+    }
+}
+
+impl<const N: usize> fmt::Debug for CFixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !f.alternate() {
+            return write!(f, "{}", self.as_str());
+        }
+
+        write!(f, "CFixedString<{}> {{ len: {}, ", N, self.length())?;
+        write_escaped(self.as_str().chars(), f)?;
+        f.write_str(" }")
+    }
+}
+
+impl<const N: usize> fmt::Display for CFixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Write for CFixedString<N> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push(string).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize> Default for CFixedString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
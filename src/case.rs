@@ -0,0 +1,119 @@
+use core::{
+    iter::{self, Iterator},
+    option::Option::{None, Some},
+    result::Result::{self, Err, Ok},
+};
+
+use crate::{FixedString, FixedStringError, FixedStringView, LengthType, Utf8};
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Uppercases the first ASCII letter of every word and lowercases the
+    /// rest in place, e.g. `motor_SPEED` becomes `Motor_Speed`. Words are
+    /// separated by any ASCII character that isn't a letter or digit.
+    pub fn make_ascii_titlecase(&mut self) {
+        let mut word_start = true;
+        for unit in self.as_units_mut() {
+            if word_start {
+                unit.make_ascii_uppercase();
+            } else {
+                unit.make_ascii_lowercase();
+            }
+            word_start = unit.is_ascii() && !unit.is_ascii_alphanumeric();
+        }
+    }
+
+    /// Returns the content in snake case as a new `FixedString` of capacity
+    /// `M`, e.g. `motorSpeed` and `HTTPServer` become `motor_speed` and
+    /// `http_server`
+    pub fn to_snake_case<const M: usize>(
+        &self,
+    ) -> Result<FixedString<M, Utf8, L>, FixedStringError> {
+        let requested = words(self.as_str())
+            .enumerate()
+            .map(|(index, word)| word.len().saturating_add(usize::from(index > 0)))
+            .sum();
+        check_fits::<M>(requested)?;
+
+        let mut res = FixedString::<M, Utf8, L>::new();
+        for (index, word) in words(self.as_str()).enumerate() {
+            if index > 0 {
+                res.push("_")?;
+            }
+            let start = res.length();
+            res.push(word)?;
+            if let Some(units) = res.as_units_mut().get_mut(start..) {
+                units.make_ascii_lowercase();
+            }
+        }
+        Ok(res)
+    }
+
+    /// Returns the content in camel case as a new `FixedString` of capacity
+    /// `M`, e.g. `motor_speed` and `HTTP-server` become `motorSpeed` and
+    /// `httpServer`
+    pub fn to_camel_case<const M: usize>(
+        &self,
+    ) -> Result<FixedString<M, Utf8, L>, FixedStringError> {
+        let requested = words(self.as_str()).map(str::len).sum();
+        check_fits::<M>(requested)?;
+
+        let mut res = FixedString::<M, Utf8, L>::new();
+        for (index, word) in words(self.as_str()).enumerate() {
+            let start = res.length();
+            res.push(word)?;
+            let units = res.as_units_mut().get_mut(start..).unwrap_or_default();
+            units.make_ascii_lowercase();
+            if let (true, Some(first)) = (index > 0, units.first_mut()) {
+                first.make_ascii_uppercase();
+            }
+        }
+        Ok(res)
+    }
+}
+
+/// Fails with an `Overflow` if `requested` bytes don't fit in an empty string
+/// of capacity `M`
+fn check_fits<const M: usize>(requested: usize) -> Result<(), FixedStringError> {
+    if requested > M {
+        return Err(FixedStringError::Overflow {
+            requested,
+            available: M,
+        });
+    }
+    Ok(())
+}
+
+/// Iterates over the words of an identifier, separated by ASCII characters
+/// that aren't letters or digits and by changes from lower to upper case
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    let bytes = text.as_bytes();
+    let mut position = 0;
+    iter::from_fn(move || {
+        let is_separator = |byte: u8| byte.is_ascii() && !byte.is_ascii_alphanumeric();
+        while bytes.get(position).is_some_and(|&byte| is_separator(byte)) {
+            position = position.saturating_add(1);
+        }
+        if position >= bytes.len() {
+            return None;
+        }
+
+        let start = position;
+        position = position.saturating_add(1);
+        while let Some(&byte) = bytes.get(position) {
+            let previous = bytes.get(position.saturating_sub(1)).copied();
+            let previous = previous.unwrap_or_default();
+            let next = bytes
+                .get(position.saturating_add(1))
+                .copied()
+                .unwrap_or_default();
+            let boundary = is_separator(byte)
+                || (byte.is_ascii_uppercase()
+                    && (!previous.is_ascii_uppercase() || next.is_ascii_lowercase()));
+            if boundary {
+                break;
+            }
+            position = position.saturating_add(1);
+        }
+        text.get(start..position)
+    })
+}
@@ -0,0 +1,136 @@
+use core::{
+    fmt,
+    iter::Iterator,
+    option::Option::Some,
+    result::Result::{self, Err, Ok},
+};
+
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+/// A string spread over a slice of `FixedString`s, filled one after another.
+/// Lets a message larger than any single buffer be assembled from several
+/// small, reusable ones. A character is never split between two pieces.
+pub struct FixedStringChain<'a, const N: usize> {
+    pieces: &'a mut [FixedString<N>],
+    /// Piece currently written to. All following pieces are empty.
+    current: usize,
+}
+
+impl<'a, const N: usize> FixedStringChain<'a, N> {
+    /// Links `pieces` into an empty chain, clearing them
+    pub fn new(pieces: &'a mut [FixedString<N>]) -> Self {
+        pieces.iter_mut().for_each(FixedString::clear);
+        Self { pieces, current: 0 }
+    }
+
+    /// Returns the total length of all pieces
+    pub fn length(&self) -> usize {
+        self.pieces.iter().map(FixedString::length).sum()
+    }
+
+    /// Returns the total capacity of all pieces
+    pub fn capacity(&self) -> usize {
+        N.saturating_mul(self.pieces.len())
+    }
+
+    /// Returns `true` if no piece holds any content
+    pub fn is_empty(&self) -> bool {
+        self.pieces.iter().all(|piece| piece.length() == 0)
+    }
+
+    /// Clears all pieces
+    pub fn clear(&mut self) {
+        self.pieces.iter_mut().for_each(FixedString::clear);
+        self.current = 0;
+    }
+
+    /// Appends a string slice, continuing in the next piece when the current
+    /// one is full. Nothing is appended if it doesn't fit.
+    pub fn push(&mut self, string: &str) -> Result<(), FixedStringError> {
+        let (current, length) = (self.current, self.current_length());
+        let mut rest = string;
+        while !rest.is_empty() {
+            let Some(piece) = self.pieces.get_mut(self.current) else {
+                let available = N
+                    .saturating_mul(self.pieces.len().saturating_sub(current))
+                    .saturating_sub(length);
+                self.rollback(current, length);
+                return Err(FixedStringError::Overflow {
+                    requested: string.len(),
+                    available,
+                });
+            };
+
+            let split = rest.floor_char_boundary(N.saturating_sub(piece.length));
+            let (head, tail) = rest.split_at_checked(split).unwrap_or_default();
+            piece.push(head)?;
+            rest = tail;
+
+            if !rest.is_empty() {
+                self.current = self.current.saturating_add(1);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Appends a character
+    pub fn push_char(&mut self, character: char) -> Result<(), FixedStringError> {
+        self.push(character.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Iterates over the content of the non-empty pieces, in order
+    pub fn pieces(&self) -> impl Iterator<Item = &str> + '_ {
+        self.pieces
+            .iter()
+            .map(FixedStringView::as_str)
+            .filter(|piece| !piece.is_empty())
+    }
+
+    /// Iterates over the characters of all pieces
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.pieces().flat_map(str::chars)
+    }
+
+    /// Returns the underlying pieces
+    pub fn into_inner(self) -> &'a mut [FixedString<N>] {
+        self.pieces
+    }
+
+    fn current_length(&self) -> usize {
+        self.pieces
+            .get(self.current)
+            .map_or(0, |piece| piece.length)
+    }
+
+    fn rollback(&mut self, current: usize, length: usize) {
+        for piece in self.pieces.iter_mut().skip(current.saturating_add(1)) {
+            piece.clear();
+        }
+        if let Some(piece) = self.pieces.get_mut(current) {
+            piece.length = length;
+        }
+        self.current = current;
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedStringChain<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.pieces()).finish()
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedStringChain<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for piece in self.pieces() {
+            f.write_str(piece)?;
+        }
+        fmt::Result::Ok(())
+    }
+}
+
+impl<const N: usize> fmt::Write for FixedStringChain<'_, N> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push(string).map_err(|_| fmt::Error)
+    }
+}
@@ -0,0 +1,104 @@
+use core::{
+    fmt,
+    ops::FnMut,
+    option::Option::{self, None, Some},
+    result::Result::{self, Ok},
+};
+
+use crate::{FixedString, FixedStringView};
+
+/// Formats output of any length through a buffer of `N` bytes. Whenever the
+/// buffer fills up, its content is handed to a flush callback, e.g. writing
+/// to a UART or a socket, and the buffer is reused.
+///
+/// Characters are never split between two flushes.
+pub struct ChunkedWriter<const N: usize, F, E>
+where
+    F: FnMut(&str) -> Result<(), E>,
+{
+    buffer: FixedString<N>,
+    flush: F,
+    /// Error returned by the callback during a `fmt::Write` call
+    error: Option<E>,
+}
+
+impl<const N: usize, F, E> ChunkedWriter<N, F, E>
+where
+    F: FnMut(&str) -> Result<(), E>,
+{
+    /// Creates a new `ChunkedWriter` calling `flush` with every full chunk
+    pub fn new(flush: F) -> Self {
+        const {
+            assert!(
+                N >= 4,
+                "the capacity of a ChunkedWriter has to fit any character"
+            )
+        };
+
+        Self {
+            buffer: FixedString::new(),
+            flush,
+            error: None,
+        }
+    }
+
+    /// Returns the content not flushed yet
+    pub fn pending(&self) -> &str {
+        self.buffer.as_str()
+    }
+
+    /// Appends a string slice, flushing each time the buffer fills up
+    pub fn push(&mut self, string: &str) -> Result<(), E> {
+        let mut rest = string;
+        while !rest.is_empty() {
+            let split = rest.floor_char_boundary(N.saturating_sub(self.buffer.length()));
+            if split == 0 {
+                self.flush()?;
+                continue;
+            }
+
+            // Fits, as split is limited to the remaining capacity
+            let (head, tail) = rest.split_at_checked(split).unwrap_or_default();
+            let _ = self.buffer.push(head);
+            rest = tail;
+            if self.buffer.is_full() {
+                self.flush()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Hands the buffered content to the callback, if there is any
+    pub fn flush(&mut self) -> Result<(), E> {
+        if self.buffer.length() == 0 {
+            return Ok(());
+        }
+
+        (self.flush)(self.buffer.as_str())?;
+        self.buffer.clear();
+        Ok(())
+    }
+
+    /// Returns the error of the callback that made a `fmt::Write` call fail
+    pub fn take_error(&mut self) -> Option<E> {
+        self.error.take()
+    }
+
+    /// Flushes the remaining content, consuming the writer
+    pub fn finish(mut self) -> Result<(), E> {
+        self.flush()
+    }
+}
+
+impl<const N: usize, F, E> fmt::Write for ChunkedWriter<N, F, E>
+where
+    F: FnMut(&str) -> Result<(), E>,
+{
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push(string).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
@@ -0,0 +1,41 @@
+use core::{
+    iter::Iterator,
+    option::Option::{self, None, Some},
+};
+
+/// Returns the longest prefix shared by all `strings`, empty if there are none
+pub fn common_prefix<'a>(strings: &[&'a str]) -> &'a str {
+    let Some((first, rest)) = strings.split_first() else {
+        return "";
+    };
+
+    let mut length = first.len();
+    for string in rest {
+        length = first
+            .get(..length)
+            .unwrap_or_default()
+            .char_indices()
+            .zip(string.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(length.min(string.len()), |((index, _), _)| index);
+    }
+
+    first.get(..length).unwrap_or_default()
+}
+
+/// Returns what can be appended to `typed` without ambiguity, given the
+/// `candidates` starting with it. `None` if no candidate does.
+pub(crate) fn complete_from<'a>(typed: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let mut prefix: Option<&'a str> = None;
+    for candidate in candidates
+        .iter()
+        .filter(|candidate| candidate.starts_with(typed))
+    {
+        prefix = Some(match prefix {
+            Some(prefix) => common_prefix(&[prefix, candidate]),
+            None => candidate,
+        });
+    }
+
+    prefix.and_then(|prefix| prefix.get(typed.len()..))
+}
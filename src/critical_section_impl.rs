@@ -0,0 +1,58 @@
+use core::{
+    cell::RefCell,
+    default::Default,
+    ops::FnOnce,
+    result::Result::{self, Err, Ok},
+};
+
+use critical_section::Mutex;
+
+use crate::{FixedString, FixedStringError};
+
+/// A `FixedString` that can be placed in a `static` and shared between
+/// interrupt handlers and the main loop. Every access runs inside a critical
+/// section.
+pub struct StaticFixedString<const N: usize> {
+    inner: Mutex<RefCell<FixedString<N>>>,
+}
+
+impl<const N: usize> StaticFixedString<N> {
+    /// Creates a new empty `StaticFixedString`
+    pub const fn new() -> Self {
+        Self {
+            inner: Mutex::new(RefCell::new(FixedString::new())),
+        }
+    }
+
+    /// Runs `f` with exclusive access to the string, inside a critical
+    /// section. Returns `Busy` if called again from within `f`.
+    pub fn with<R>(&self, f: impl FnOnce(&mut FixedString<N>) -> R) -> Result<R, FixedStringError> {
+        critical_section::with(|cs| match self.inner.borrow(cs).try_borrow_mut() {
+            Ok(mut string) => Ok(f(&mut string)),
+            Err(_) => Err(FixedStringError::Busy),
+        })
+    }
+
+    /// Returns a copy of the current content
+    pub fn load(&self) -> Result<FixedString<N>, FixedStringError> {
+        self.with(|string| *string)
+    }
+
+    /// Replaces the content with `string`. The content is left unchanged if
+    /// `string` doesn't fit.
+    pub fn store(&self, string: &str) -> Result<(), FixedStringError> {
+        let string = FixedString::new_with(string)?;
+        self.with(|current| *current = string)
+    }
+
+    /// Clears the content
+    pub fn clear(&self) -> Result<(), FixedStringError> {
+        self.with(|string| string.clear())
+    }
+}
+
+impl<const N: usize> Default for StaticFixedString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,189 @@
+use core::{
+    fmt,
+    iter::Iterator,
+    option::Option::{self, None, Some},
+    result::Result::{self, Err, Ok},
+};
+
+use crate::{FixedString, FixedStringError, FixedStringView, LengthType, TextEncoding, Utf8};
+
+const QUOTE: char = '"';
+
+/// A field of a CSV line. Quoted fields are kept as they appear in the line,
+/// with doubled quotes, and unescaped when displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvField<'a> {
+    raw: &'a str,
+    quoted: bool,
+}
+
+impl<'a> CsvField<'a> {
+    /// Returns the field as it appears in the line, without surrounding quotes
+    pub fn raw(&self) -> &'a str {
+        self.raw
+    }
+
+    /// Returns `true` if the field was quoted
+    pub fn is_quoted(&self) -> bool {
+        self.quoted
+    }
+
+    /// Returns the field as a `&str` if it doesn't contain escaped quotes, so
+    /// it can be used without copying
+    pub fn as_str(&self) -> Option<&'a str> {
+        match self.quoted && self.raw.contains(QUOTE) {
+            true => None,
+            false => Some(self.raw),
+        }
+    }
+
+    /// Copies the unescaped field into a new `FixedString`
+    pub fn to_fixed_string<const M: usize>(&self) -> Result<FixedString<M>, FixedStringError> {
+        FixedString::format(format_args!("{}", self))
+    }
+}
+
+/// Writes the unescaped field
+impl fmt::Display for CsvField<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.quoted {
+            return f.write_str(self.raw);
+        }
+
+        for (i, part) in self.raw.split("\"\"").enumerate() {
+            if i > 0 {
+                fmt::Write::write_char(f, QUOTE)?;
+            }
+            f.write_str(part)?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the fields of a CSV line, honoring quoted fields
+#[derive(Debug, Clone)]
+pub struct CsvFields<'a> {
+    remaining: Option<&'a str>,
+    delimiter: char,
+}
+
+impl<'a> CsvFields<'a> {
+    /// Creates an iterator over the fields of `line`, separated by `delimiter`
+    pub fn new(line: &'a str, delimiter: char) -> Self {
+        Self {
+            remaining: match line.is_empty() {
+                true => None,
+                false => Some(line),
+            },
+            delimiter,
+        }
+    }
+}
+
+impl<'a> Iterator for CsvFields<'a> {
+    type Item = CsvField<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.remaining?;
+
+        let (field, rest) = match line.strip_prefix(QUOTE) {
+            Some(quoted) => {
+                // Find the closing quote, skipping doubled quotes
+                let mut end = quoted.len();
+                let mut index = 0;
+                while let Some(offset) = quoted.get(index..).and_then(|rest| rest.find(QUOTE)) {
+                    let position = index.saturating_add(offset);
+                    if quoted
+                        .get(position.saturating_add(1)..)
+                        .is_some_and(|rest| rest.starts_with(QUOTE))
+                    {
+                        index = position.saturating_add(2);
+                    } else {
+                        end = position;
+                        break;
+                    }
+                }
+
+                let after = quoted.get(end.saturating_add(1)..).unwrap_or("");
+                let rest = after.find(self.delimiter).and_then(|i| after.get(i..));
+                (
+                    CsvField {
+                        raw: quoted.get(..end).unwrap_or_default(),
+                        quoted: true,
+                    },
+                    rest,
+                )
+            }
+            None => match line.find(self.delimiter) {
+                Some(i) => (
+                    CsvField {
+                        raw: line.get(..i).unwrap_or_default(),
+                        quoted: false,
+                    },
+                    line.get(i..),
+                ),
+                None => (
+                    CsvField {
+                        raw: line,
+                        quoted: false,
+                    },
+                    None,
+                ),
+            },
+        };
+
+        self.remaining = rest.and_then(|rest| rest.get(self.delimiter.len_utf8()..));
+        Some(field)
+    }
+}
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Iterates over the comma separated fields of the content
+    pub fn csv_fields(&self) -> CsvFields<'_> {
+        CsvFields::new(self.as_str(), ',')
+    }
+}
+
+impl<const N: usize, E: TextEncoding, L: LengthType> FixedString<N, E, L> {
+    /// Appends `field`, quoted if it contains a comma, quote or line break,
+    /// with quotes doubled
+    pub fn push_csv_field(&mut self, field: &str) -> Result<(), FixedStringError> {
+        let needs_quotes = field.contains([',', QUOTE, '\r', '\n']);
+        if !needs_quotes {
+            return self.push(field);
+        }
+
+        let quotes = field.matches(QUOTE).count();
+        self.grown_length(
+            E::encoded_len(field)
+                .saturating_add(quotes)
+                .saturating_add(2),
+        )?;
+
+        self.push("\"")?;
+        for (i, part) in field.split(QUOTE).enumerate() {
+            if i > 0 {
+                self.push("\"\"")?;
+            }
+            self.push(part)?;
+        }
+        self.push("\"")
+    }
+
+    /// Appends `fields` as a comma separated record, without a line break
+    pub fn push_csv_record(&mut self, fields: &[&str]) -> Result<(), FixedStringError> {
+        let length = self.length;
+        for (i, field) in fields.iter().enumerate() {
+            let pushed = match i {
+                0 => self.push_csv_field(field),
+                _ => self.push(",").and_then(|_| self.push_csv_field(field)),
+            };
+            if let Err(err) = pushed {
+                self.length = length;
+                return Err(err);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use core::{
+    iter::Iterator,
+    ops::FnMut,
+    option::Option::{self, Some},
+    result::Result::{self, Err, Ok},
+};
+
+use crate::FixedStringError;
+
+/// Reads through text from the start, keeping track of the position, as a
+/// small parser for protocol handlers. Failed reads leave the position
+/// unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Creates a new `Cursor` at the start of `text`
+    pub const fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+
+    /// Returns the byte offset of the next character
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the text not read yet
+    pub fn rest(&self) -> &'a str {
+        self.text.get(self.position..).unwrap_or_default()
+    }
+
+    /// Returns `true` if all of the text has been read
+    pub fn is_at_end(&self) -> bool {
+        self.position == self.text.len()
+    }
+
+    /// Returns the next character without reading it
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Reads the next character
+    pub fn next_char(&mut self) -> Option<char> {
+        let character = self.peek()?;
+        self.position = self.position.saturating_add(character.len_utf8());
+        Some(character)
+    }
+
+    /// Reads characters as long as `predicate` holds, returning them
+    pub fn take_while(&mut self, mut predicate: impl FnMut(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let length = rest
+            .find(|character| !predicate(character))
+            .unwrap_or(rest.len());
+        self.position = self.position.saturating_add(length);
+        rest.get(..length).unwrap_or_default()
+    }
+
+    /// Skips any Unicode whitespace
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    /// Reads `expected`, returning a `ParseError` if the text continues with
+    /// something else
+    pub fn expect(&mut self, expected: &str) -> Result<(), FixedStringError> {
+        if !self.rest().starts_with(expected) {
+            return Err(FixedStringError::ParseError);
+        }
+
+        self.position = self.position.saturating_add(expected.len());
+        Ok(())
+    }
+
+    /// Reads a decimal `u32`, returning a `ParseError` if there are no digits
+    /// or the value doesn't fit
+    pub fn parse_u32(&mut self) -> Result<u32, FixedStringError> {
+        let rest = self.rest();
+        let length = rest
+            .bytes()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        let value = rest
+            .get(..length)
+            .unwrap_or_default()
+            .parse()
+            .map_err(|_| FixedStringError::ParseError)?;
+        self.position = self.position.saturating_add(length);
+        Ok(value)
+    }
+}
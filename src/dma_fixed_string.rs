@@ -0,0 +1,107 @@
+use core::{
+    default::Default,
+    fmt,
+    ops::Deref,
+    result::Result::{self, Err, Ok},
+    str,
+};
+
+use crate::{CHARACTER, FixedString, FixedStringError, FixedStringRef};
+
+/// Alignment of a `DmaFixedString`, the data cache line size of the Cortex-M7
+pub const DMA_ALIGNMENT: usize = 32;
+
+/// A `FixedString` whose buffer is aligned to `DMA_ALIGNMENT` and occupies
+/// whole cache lines, so it can be the target of DMA transfers that require
+/// cache maintenance. `N` has to be a multiple of `DMA_ALIGNMENT`, which
+/// keeps the length in a cache line of its own.
+///
+/// The DMA engine is given the address of the buffer, so the string must not
+/// be moved during a transfer, e.g. by placing it in a `static`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(C, align(32))]
+pub struct DmaFixedString<const N: usize> {
+    inner: FixedString<N>,
+}
+
+impl<const N: usize> DmaFixedString<N> {
+    /// Creates a new empty `DmaFixedString`.
+    pub const fn new() -> Self {
+        const {
+            assert!(
+                N.is_multiple_of(DMA_ALIGNMENT),
+                "the capacity of a DmaFixedString has to be a multiple of DMA_ALIGNMENT"
+            )
+        };
+
+        Self {
+            inner: FixedString::new(),
+        }
+    }
+
+    /// Returns the full capacity of the buffer, for a DMA transfer to receive
+    /// into. Use `commit` afterwards to set the length of the received data.
+    pub fn as_dma_slice(&mut self) -> &mut [CHARACTER] {
+        &mut self.inner.buffer
+    }
+
+    /// Sets the length after data was received into the buffer, validating
+    /// that the received data is UTF-8
+    pub fn commit(&mut self, length: usize) -> Result<(), FixedStringError> {
+        if length > N {
+            return Err(FixedStringError::Overflow {
+                requested: length,
+                available: N,
+            });
+        }
+
+        let bytes = self.inner.raw().get(..length).unwrap_or_default();
+        if str::from_utf8(bytes).is_err() {
+            return Err(FixedStringError::InvalidUtf8);
+        }
+
+        unsafe { self.inner.set_len(length) };
+        Ok(())
+    }
+
+    /// Appends a string slice, if it fits
+    pub fn push(&mut self, string: &str) -> Result<(), FixedStringError> {
+        self.inner.push(string)
+    }
+
+    /// Appends a character, if it fits
+    pub fn push_char(&mut self, character: char) -> Result<(), FixedStringError> {
+        self.inner.push_char(character)
+    }
+
+    /// Shortens the content to `length` bytes, which has to be on a character
+    /// boundary
+    pub fn truncate(&mut self, length: usize) -> Result<(), FixedStringError> {
+        FixedStringRef::truncate(&mut self.inner, length)
+    }
+
+    /// Clears the content, leaving the buffer as it is
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+}
+
+impl<const N: usize> Deref for DmaFixedString<N> {
+    type Target = FixedString<N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<const N: usize> fmt::Write for DmaFixedString<N> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push(string).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize> Default for DmaFixedString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
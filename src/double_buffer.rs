@@ -0,0 +1,108 @@
+use core::{
+    cell::UnsafeCell,
+    default::Default,
+    marker::Sync,
+    ops::FnOnce,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use crate::FixedString;
+
+/// Bit of `state` holding the index of the published buffer
+const FRONT: u8 = 0b01;
+/// Bit of `state` set while the reader accesses the published buffer
+const READING: u8 = 0b10;
+
+/// Two `FixedString`s where a producer, e.g. an interrupt handler, writes into
+/// one while a consumer reads the other. The producer publishes what it wrote
+/// by swapping the buffers.
+pub struct DoubleBufferedFixedString<const N: usize> {
+    buffers: [UnsafeCell<FixedString<N>>; 2],
+    state: AtomicU8,
+}
+
+// The writer only accesses the buffer that isn't published, and the reader
+// only the published one while `READING` prevents swapping
+unsafe impl<const N: usize> Sync for DoubleBufferedFixedString<N> {}
+
+impl<const N: usize> DoubleBufferedFixedString<N> {
+    /// Creates a new `DoubleBufferedFixedString` with both buffers empty
+    pub const fn new() -> Self {
+        Self {
+            buffers: [const { UnsafeCell::new(FixedString::new()) }; 2],
+            state: AtomicU8::new(0),
+        }
+    }
+
+    /// Splits into the writing and the reading half
+    pub fn split(&mut self) -> (DoubleBufferWriter<'_, N>, DoubleBufferReader<'_, N>) {
+        (
+            DoubleBufferWriter { buffer: self },
+            DoubleBufferReader { buffer: self },
+        )
+    }
+
+    /// Returns the buffer selected by the `FRONT` bit of `index`
+    fn buffer(&self, index: u8) -> &UnsafeCell<FixedString<N>> {
+        let [first, second] = &self.buffers;
+        if index & FRONT == 0 { first } else { second }
+    }
+}
+
+impl<const N: usize> Default for DoubleBufferedFixedString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The producing half of a `DoubleBufferedFixedString`
+pub struct DoubleBufferWriter<'a, const N: usize> {
+    buffer: &'a DoubleBufferedFixedString<N>,
+}
+
+impl<const N: usize> DoubleBufferWriter<'_, N> {
+    /// Returns the buffer being written
+    pub fn back(&mut self) -> &mut FixedString<N> {
+        let back = (self.buffer.state.load(Ordering::Acquire) & FRONT) ^ 1;
+        unsafe { &mut *self.buffer.buffer(back).get() }
+    }
+
+    /// Publishes the buffer being written and continues with the other one,
+    /// cleared. Returns `false` without swapping if the reader currently
+    /// accesses the published buffer, leaving the written content in place.
+    pub fn swap(&mut self) -> bool {
+        let front = self.buffer.state.load(Ordering::Acquire) & FRONT;
+        let swapped = self
+            .buffer
+            .state
+            .compare_exchange(front, front ^ 1, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok();
+        if swapped {
+            self.back().clear();
+        }
+
+        swapped
+    }
+}
+
+/// The consuming half of a `DoubleBufferedFixedString`
+pub struct DoubleBufferReader<'a, const N: usize> {
+    buffer: &'a DoubleBufferedFixedString<N>,
+}
+
+impl<const N: usize> DoubleBufferReader<'_, N> {
+    /// Runs `f` with the most recently published buffer. The writer can't
+    /// swap while `f` runs.
+    pub fn read<R>(&mut self, f: impl FnOnce(&FixedString<N>) -> R) -> R {
+        let front = self.buffer.state.fetch_or(READING, Ordering::AcqRel) & FRONT;
+        let result = f(unsafe { &*self.buffer.buffer(front).get() });
+        self.buffer.state.fetch_and(!READING, Ordering::Release);
+
+        result
+    }
+
+    /// Returns a copy of the most recently published buffer
+    pub fn load(&mut self) -> FixedString<N> {
+        self.read(|string| *string)
+    }
+}
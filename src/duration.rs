@@ -0,0 +1,61 @@
+use core::{
+    result::Result::{self, Err, Ok},
+    time::Duration,
+};
+
+use crate::{FixedString, FixedStringError, LengthType, TextEncoding};
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+impl<const N: usize, E: TextEncoding, L: LengthType> FixedString<N, E, L> {
+    /// Appends a duration, like an uptime, as `HH:MM:SS`, prefixed with the
+    /// number of days if there are any, e.g. `1d 02:03:04`. `decimals` digits
+    /// of the fraction of a second are added after a point, truncated. More
+    /// than 9 decimals are rejected with a `FormatError`.
+    pub fn push_duration(
+        &mut self,
+        duration: Duration,
+        decimals: u8,
+    ) -> Result<(), FixedStringError> {
+        if decimals > 9 {
+            return Err(FixedStringError::FormatError);
+        }
+
+        let length = self.length;
+        let pushed = self.push_duration_fields(duration, decimals);
+        if pushed.is_err() {
+            self.length = length;
+        }
+        pushed
+    }
+
+    fn push_duration_fields(
+        &mut self,
+        duration: Duration,
+        decimals: u8,
+    ) -> Result<(), FixedStringError> {
+        let seconds = duration.as_secs();
+        let days = seconds / SECONDS_PER_DAY;
+        if days > 0 {
+            self.push_uint_padded(days, 0, 10)?;
+            self.push("d ")?;
+        }
+
+        self.push_uint_padded(seconds % SECONDS_PER_DAY / 3600, 2, 10)?;
+        self.push(":")?;
+        self.push_uint_padded(seconds % 3600 / 60, 2, 10)?;
+        self.push(":")?;
+        self.push_uint_padded(seconds % 60, 2, 10)?;
+
+        if decimals > 0 {
+            let fraction = duration
+                .subsec_nanos()
+                .checked_div(10u32.pow(9u32.saturating_sub(decimals as u32)))
+                .unwrap_or_default();
+            self.push(".")?;
+            self.push_uint_padded(fraction, decimals as usize, 10)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use core::{
+    result::Result::{self, Err, Ok},
+    str,
+};
+
+use embedded_io::{BufRead, Error, ErrorKind, ErrorType, Read, Write};
+
+use crate::{FixedString, FixedStringError, copy_within_checked};
+
+impl Error for FixedStringError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            FixedStringError::AlreadyAssigned => ErrorKind::AlreadyExists,
+            FixedStringError::Overflow { .. } => ErrorKind::WriteZero,
+            FixedStringError::InvalidIndex { .. } => ErrorKind::InvalidInput,
+            FixedStringError::FormatError => ErrorKind::Other,
+            FixedStringError::InvalidUtf8 => ErrorKind::InvalidData,
+            FixedStringError::InteriorNul { .. } => ErrorKind::InvalidData,
+            FixedStringError::InvalidUtf16 => ErrorKind::InvalidData,
+            FixedStringError::ParseError => ErrorKind::InvalidData,
+            FixedStringError::InvalidHex { .. } => ErrorKind::InvalidData,
+            FixedStringError::InvalidBase32 { .. } => ErrorKind::InvalidData,
+            FixedStringError::Busy => ErrorKind::Other,
+        }
+    }
+}
+
+impl<const N: usize> FixedString<N> {
+    /// Remove `count` bytes from the front of the string
+    fn remove_front(&mut self, count: usize) {
+        copy_within_checked(&mut self.buffer, count..self.length, 0);
+        self.length = self.length.saturating_sub(count);
+    }
+}
+
+impl<const N: usize> ErrorType for FixedString<N> {
+    type Error = FixedStringError;
+}
+
+/// Appends UTF-8 encoded bytes to the `FixedString`. If the buffer doesn't
+/// fit, as many whole characters as possible are written.
+impl<const N: usize> Write for FixedString<N> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        match self.write_utf8(buf)? {
+            0 if !buf.is_empty() => Err(FixedStringError::Overflow {
+                requested: buf.len(),
+                available: N.saturating_sub(self.length),
+            }),
+            written => Ok(written),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Consumes bytes from the front of the `FixedString`. Only whole characters
+/// are read, so `buf` has to fit at least the first character.
+impl<const N: usize> Read for FixedString<N> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let content = unsafe { str::from_utf8_unchecked(self.as_units()) };
+        let read_len = content.floor_char_boundary(buf.len());
+
+        if read_len == 0 && !buf.is_empty() && self.length != 0 {
+            let first_char_len = content.chars().next().map_or(0, char::len_utf8);
+            return Err(FixedStringError::Overflow {
+                requested: first_char_len,
+                available: buf.len(),
+            });
+        }
+
+        if let (Some(buf), Some(units)) = (buf.get_mut(..read_len), self.as_units().get(..read_len))
+        {
+            buf.copy_from_slice(units);
+        }
+        self.remove_front(read_len);
+
+        Ok(read_len)
+    }
+}
+
+impl<const N: usize> BufRead for FixedString<N> {
+    fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+        Ok(self.as_units())
+    }
+
+    /// Consumes `amt` bytes, rounded up to the next character boundary
+    fn consume(&mut self, amt: usize) {
+        let content = unsafe { str::from_utf8_unchecked(self.as_units()) };
+        self.remove_front(content.ceil_char_boundary(amt));
+    }
+}
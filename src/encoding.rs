@@ -0,0 +1,160 @@
+use core::{char, convert::Into, fmt, iter::Iterator, str};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Describes the code units stored in the buffer of a `FixedString`
+pub trait Encoding: sealed::Sealed + Copy + Eq {
+    /// The code unit stored in the buffer
+    type Unit: Copy + Eq + fmt::Debug + fmt::LowerHex + fmt::UpperHex + fmt::Binary + Into<u32>;
+    /// Unit used for the unused part of the buffer
+    const NONE: Self::Unit;
+}
+
+/// An `Encoding` where the code units encode text
+pub trait TextEncoding: Encoding {
+    /// Returns the number of units needed to encode `string`
+    fn encoded_len(string: &str) -> usize;
+    /// Encodes `string` into the start of `out`, which has room for at least
+    /// `encoded_len(string)` units
+    fn encode(string: &str, out: &mut [Self::Unit]);
+    /// Iterates over the characters of units produced by `encode`
+    fn decode(units: &[Self::Unit]) -> impl Iterator<Item = char> + Clone + '_;
+    /// Writes units produced by `encode` to a formatter
+    fn fmt(units: &[Self::Unit], f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Writes `characters` honoring the width, fill, alignment and precision of
+/// the formatter, like `Formatter::pad` does for a `&str`
+fn pad_chars(
+    characters: impl Iterator<Item = char> + Clone,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let characters = characters.take(f.precision().unwrap_or(usize::MAX));
+    let padding = f
+        .width()
+        .unwrap_or(0)
+        .saturating_sub(characters.clone().count());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+        _ => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        fmt::Write::write_char(f, fill)?;
+    }
+    for character in characters {
+        fmt::Write::write_char(f, character)?;
+    }
+    for _ in 0..after {
+        fmt::Write::write_char(f, fill)?;
+    }
+    fmt::Result::Ok(())
+}
+
+/// UTF-8 encoding with byte sized code units. The default for `FixedString`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utf8;
+
+/// UTF-16 encoding with 16 bit code units
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utf16;
+
+/// UTF-32 encoding, storing one character per 32 bit code unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utf32;
+
+/// Raw bytes without any text semantics, used by `FixedBytes`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bytes;
+
+impl sealed::Sealed for Utf8 {}
+impl sealed::Sealed for Utf16 {}
+impl sealed::Sealed for Utf32 {}
+impl sealed::Sealed for Bytes {}
+
+impl Encoding for Utf8 {
+    type Unit = u8;
+    const NONE: Self::Unit = 0;
+}
+
+impl TextEncoding for Utf8 {
+    fn encoded_len(string: &str) -> usize {
+        string.len()
+    }
+
+    fn encode(string: &str, out: &mut [Self::Unit]) {
+        if let Some(out) = out.get_mut(..string.len()) {
+            out.copy_from_slice(string.as_bytes());
+        }
+    }
+
+    fn decode(units: &[Self::Unit]) -> impl Iterator<Item = char> + Clone + '_ {
+        unsafe { str::from_utf8_unchecked(units) }.chars()
+    }
+
+    fn fmt(units: &[Self::Unit], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(unsafe { str::from_utf8_unchecked(units) })
+    }
+}
+
+impl Encoding for Utf16 {
+    type Unit = u16;
+    const NONE: Self::Unit = 0;
+}
+
+impl TextEncoding for Utf16 {
+    fn encoded_len(string: &str) -> usize {
+        string.encode_utf16().count()
+    }
+
+    fn encode(string: &str, out: &mut [Self::Unit]) {
+        for (slot, unit) in out.iter_mut().zip(string.encode_utf16()) {
+            *slot = unit;
+        }
+    }
+
+    fn decode(units: &[Self::Unit]) -> impl Iterator<Item = char> + Clone + '_ {
+        char::decode_utf16(units.iter().copied())
+            .map(|character| character.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    fn fmt(units: &[Self::Unit], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad_chars(Self::decode(units), f)
+    }
+}
+
+impl Encoding for Utf32 {
+    type Unit = u32;
+    const NONE: Self::Unit = 0;
+}
+
+impl TextEncoding for Utf32 {
+    fn encoded_len(string: &str) -> usize {
+        string.chars().count()
+    }
+
+    fn encode(string: &str, out: &mut [Self::Unit]) {
+        for (slot, character) in out.iter_mut().zip(string.chars()) {
+            *slot = character as u32;
+        }
+    }
+
+    fn decode(units: &[Self::Unit]) -> impl Iterator<Item = char> + Clone + '_ {
+        units
+            .iter()
+            .map(|unit| char::from_u32(*unit).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    fn fmt(units: &[Self::Unit], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad_chars(Self::decode(units), f)
+    }
+}
+
+impl Encoding for Bytes {
+    type Unit = u8;
+    const NONE: Self::Unit = 0;
+}
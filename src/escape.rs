@@ -0,0 +1,60 @@
+use core::{
+    fmt::{self, Write},
+    marker::PhantomData,
+    result::Result::{self, Err, Ok},
+    write,
+};
+
+use crate::{FixedString, FixedStringError, LengthType, TextEncoding};
+
+/// Counts the units a formatted value takes up in encoding `E`
+struct UnitCounter<E> {
+    count: usize,
+    encoding: PhantomData<E>,
+}
+
+impl<E: TextEncoding> fmt::Write for UnitCounter<E> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.count = self.count.saturating_add(E::encoded_len(string));
+        fmt::Result::Ok(())
+    }
+}
+
+impl<const N: usize, E: TextEncoding, L: LengthType> FixedString<N, E, L> {
+    /// Appends `string` with special characters escaped as by
+    /// `str::escape_default`, e.g. `\n`, `\"` and `\u{7f}`
+    pub fn push_escape_default(&mut self, string: &str) -> Result<(), FixedStringError> {
+        self.push_display(string.escape_default())
+    }
+
+    /// Appends `string` with special characters escaped as by
+    /// `str::escape_debug`, which leaves printable non-ASCII characters as is
+    pub fn push_escape_debug(&mut self, string: &str) -> Result<(), FixedStringError> {
+        self.push_display(string.escape_debug())
+    }
+
+    /// Appends raw bytes with non-printable bytes escaped as by
+    /// `<[u8]>::escape_ascii`, e.g. `\n` and `\xNN`
+    pub fn push_escape_ascii(&mut self, bytes: &[u8]) -> Result<(), FixedStringError> {
+        self.push_display(bytes.escape_ascii())
+    }
+
+    /// Appends a formatted value if all of it fits
+    pub(crate) fn push_display(
+        &mut self,
+        value: impl fmt::Display,
+    ) -> Result<(), FixedStringError> {
+        let mut counter = UnitCounter::<E> {
+            count: 0,
+            encoding: PhantomData,
+        };
+        write!(counter, "{}", value).map_err(|_| FixedStringError::FormatError)?;
+
+        self.grown_length(counter.count)?;
+
+        match write!(self, "{}", value) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(FixedStringError::FormatError),
+        }
+    }
+}
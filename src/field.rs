@@ -0,0 +1,105 @@
+use core::{
+    iter::Iterator,
+    ops::Range,
+    option::Option::Some,
+    result::Result::{self, Err, Ok},
+};
+
+use crate::{FixedString, FixedStringError, LengthType, TextEncoding};
+
+/// Returns the bytes of `text` in `columns` with surrounding whitespace
+/// trimmed. Columns past the end of a shorter record are empty.
+pub(crate) fn field(text: &str, columns: Range<usize>) -> Result<&str, FixedStringError> {
+    let start = columns.start.min(text.len());
+    let end = columns.end.clamp(start, text.len());
+    match text.get(start..end) {
+        Some(field) => Ok(field.trim()),
+        None => Err(FixedStringError::InvalidIndex {
+            index: if text.is_char_boundary(start) {
+                end
+            } else {
+                start
+            },
+            length: text.len(),
+        }),
+    }
+}
+
+/// Returns the first `width` characters of `value`
+fn first_chars(value: &str, width: usize) -> &str {
+    match value.char_indices().nth(width) {
+        Some((index, _)) => value.get(..index).unwrap_or(value),
+        None => value,
+    }
+}
+
+impl<const N: usize, E: TextEncoding, L: LengthType> FixedString<N, E, L> {
+    /// Appends `value` as a left aligned column of exactly `width`
+    /// characters, padded with `fill` or truncated to its first `width`
+    /// characters
+    pub fn push_field_left(
+        &mut self,
+        value: &str,
+        width: usize,
+        fill: char,
+    ) -> Result<(), FixedStringError> {
+        let value = first_chars(value, width);
+        let padding = width.saturating_sub(value.chars().count());
+        self.push_field("", value, padding, fill, true)
+    }
+
+    /// Appends `value` as a right aligned column of exactly `width`
+    /// characters, padded with `fill` or truncated to its first `width`
+    /// characters. When padding with `'0'`, a leading sign stays in front,
+    /// e.g. `-0042`.
+    pub fn push_field_right(
+        &mut self,
+        value: &str,
+        width: usize,
+        fill: char,
+    ) -> Result<(), FixedStringError> {
+        let value = first_chars(value, width);
+        let padding = width.saturating_sub(value.chars().count());
+        let (sign, value) = match value.strip_prefix(['-', '+']) {
+            Some(digits) if fill == '0' => value
+                .split_at_checked(value.len().saturating_sub(digits.len()))
+                .unwrap_or(("", value)),
+            _ => ("", value),
+        };
+        self.push_field(sign, value, padding, fill, false)
+    }
+
+    /// Appends `sign`, `value` and `padding` copies of `fill`, with the
+    /// padding after the value if `left` or between the sign and the value
+    fn push_field(
+        &mut self,
+        sign: &str,
+        value: &str,
+        padding: usize,
+        fill: char,
+        left: bool,
+    ) -> Result<(), FixedStringError> {
+        let mut encoded = [0; 4];
+        let fill = fill.encode_utf8(&mut encoded);
+        self.grown_length(
+            E::encoded_len(sign)
+                .saturating_add(E::encoded_len(value))
+                .saturating_add(padding.saturating_mul(E::encoded_len(fill))),
+        )?;
+
+        self.push(sign)?;
+        if !left {
+            for _ in 0..padding {
+                self.push(fill)?;
+            }
+        }
+        self.push(value)?;
+        if left {
+            for _ in 0..padding {
+                self.push(fill)?;
+            }
+        }
+
+        Ok(())
+    }
+}
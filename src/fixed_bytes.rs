@@ -0,0 +1,80 @@
+use core::{
+    convert::{From, TryFrom},
+    fmt,
+    result::Result::{self, Err, Ok},
+    slice, str,
+};
+
+use crate::{Bytes, FixedString, FixedStringError, FixedStringView};
+
+/// Binary data stored in a fixed size buffer of `N` bytes. Shares the
+/// machinery of `FixedString`, but without any UTF-8 semantics.
+pub type FixedBytes<const N: usize> = FixedString<N, Bytes>;
+
+impl<const N: usize> FixedString<N, Bytes> {
+    /// Creates a new `FixedBytes` with a copy of `bytes`
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, FixedStringError> {
+        let mut res = Self::new();
+        res.extend_from_slice(bytes)?;
+        Ok(res)
+    }
+
+    /// Returns the content
+    pub fn as_bytes(&self) -> &[u8] {
+        self.as_units()
+    }
+
+    /// Returns the content for in-place modification
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        self.as_units_mut()
+    }
+
+    /// Iterates mutably over the bytes of the content
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, u8> {
+        self.as_units_mut().iter_mut()
+    }
+
+    /// Appends bytes, if they fit
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) -> Result<(), FixedStringError> {
+        self.push_units(bytes)
+    }
+
+    /// Appends a single byte, if it fits
+    pub fn push_byte(&mut self, byte: u8) -> Result<(), FixedStringError> {
+        self.push_units(&[byte])
+    }
+
+    /// Appends the content of another `FixedBytes`, if it fits
+    pub fn concat<const M: usize>(
+        &mut self,
+        other: &FixedBytes<M>,
+    ) -> Result<(), FixedStringError> {
+        self.push_units(other.as_bytes())
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedString<N, Bytes> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_bytes(), f)
+    }
+}
+
+impl<const N: usize> From<&FixedString<N>> for FixedBytes<N> {
+    fn from(fixed_string: &FixedString<N>) -> Self {
+        let mut res = Self::new();
+        let _ = res.extend_from_slice(fixed_string.as_str().as_bytes());
+        res
+    }
+}
+
+/// Create a `FixedString` from binary data, validating that it's UTF-8
+impl<const N: usize> TryFrom<&FixedBytes<N>> for FixedString<N> {
+    type Error = FixedStringError;
+
+    fn try_from(bytes: &FixedBytes<N>) -> Result<Self, Self::Error> {
+        match str::from_utf8(bytes.as_bytes()) {
+            Ok(string) => Self::new_with(string),
+            Err(_) => Err(FixedStringError::InvalidUtf8),
+        }
+    }
+}
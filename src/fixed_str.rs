@@ -0,0 +1,120 @@
+use core::{
+    convert::AsRef,
+    fmt,
+    result::Result::{self, Err, Ok},
+    str, write,
+};
+
+use crate::{CHARACTER, FixedStringError, FixedStringView, write_escaped};
+
+/// A read-only fixed string borrowed from a byte slice, like the payload of a
+/// received frame. The capacity is the length of the slice.
+#[derive(Clone, Copy)]
+pub struct FixedStr<'a> {
+    buffer: &'a [CHARACTER],
+    length: usize,
+}
+
+impl<'a> FixedStr<'a> {
+    /// Wraps `buffer`, validating that all of it is UTF-8
+    pub fn new(buffer: &'a [CHARACTER]) -> Result<Self, FixedStringError> {
+        Self::with_length(buffer, buffer.len())
+    }
+
+    /// Wraps `buffer`, where the first `length` bytes hold UTF-8 content
+    pub fn with_length(buffer: &'a [CHARACTER], length: usize) -> Result<Self, FixedStringError> {
+        let Some(content) = buffer.get(..length) else {
+            return Err(FixedStringError::Overflow {
+                requested: length,
+                available: buffer.len(),
+            });
+        };
+
+        if str::from_utf8(content).is_err() {
+            return Err(FixedStringError::InvalidUtf8);
+        }
+
+        Ok(Self { buffer, length })
+    }
+
+    /// Returns a `&str` representation of the content
+    pub fn as_str(&self) -> &'a str {
+        unsafe { str::from_utf8_unchecked(self.buffer.get(..self.length).unwrap_or_default()) }
+    }
+
+    /// Returns `true` if the content fills the whole buffer
+    pub fn is_full(&self) -> bool {
+        self.length == self.buffer.len()
+    }
+
+    /// Returns the current length
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Returns the size of the underlying buffer
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Index a byte of the buffer, which may be past the content
+    pub fn get(&self, index: usize) -> Result<&'a CHARACTER, FixedStringError> {
+        self.buffer
+            .get(index)
+            .ok_or(FixedStringError::InvalidIndex {
+                index,
+                length: self.buffer.len(),
+            })
+    }
+}
+
+impl FixedStringView for FixedStr<'_> {
+    fn as_str(&self) -> &str {
+        FixedStr::as_str(self)
+    }
+
+    fn is_full(&self) -> bool {
+        FixedStr::is_full(self)
+    }
+
+    fn length(&self) -> usize {
+        FixedStr::length(self)
+    }
+
+    fn capacity(&self) -> usize {
+        FixedStr::capacity(self)
+    }
+
+    fn get(&self, index: usize) -> Result<&CHARACTER, FixedStringError> {
+        FixedStr::get(self, index)
+    }
+}
+
+impl AsRef<str> for FixedStr<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for FixedStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !f.alternate() {
+            return write!(f, "{}", self.as_str());
+        }
+
+        write!(
+            f,
+            "FixedStr {{ capacity: {}, len: {}, ",
+            self.capacity(),
+            self.length()
+        )?;
+        write_escaped(self.as_str().chars(), f)?;
+        f.write_str(" }")
+    }
+}
+
+impl fmt::Display for FixedStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
@@ -0,0 +1,166 @@
+use core::{
+    convert::AsRef,
+    fmt,
+    result::Result::{self, Err, Ok},
+    str, write,
+};
+
+use crate::{CHARACTER, FixedStringError, FixedStringRef, FixedStringView, write_escaped};
+
+/// A fixed string backed by a caller provided buffer, like a DMA buffer or a
+/// statically placed array. The capacity is the length of the buffer.
+pub struct FixedStrMut<'a> {
+    buffer: &'a mut [CHARACTER],
+    length: usize,
+}
+
+impl<'a> FixedStrMut<'a> {
+    /// Wraps `buffer` as an empty string
+    pub fn new(buffer: &'a mut [CHARACTER]) -> Self {
+        Self { buffer, length: 0 }
+    }
+
+    /// Wraps `buffer`, where the first `length` bytes already hold UTF-8
+    /// content
+    pub fn with_length(
+        buffer: &'a mut [CHARACTER],
+        length: usize,
+    ) -> Result<Self, FixedStringError> {
+        let Some(content) = buffer.get(..length) else {
+            return Err(FixedStringError::Overflow {
+                requested: length,
+                available: buffer.len(),
+            });
+        };
+
+        if str::from_utf8(content).is_err() {
+            return Err(FixedStringError::InvalidUtf8);
+        }
+
+        Ok(Self { buffer, length })
+    }
+
+    /// Returns the underlying buffer
+    pub fn into_inner(self) -> &'a mut [CHARACTER] {
+        self.buffer
+    }
+}
+
+impl FixedStringView for FixedStrMut<'_> {
+    fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(self.buffer.get(..self.length).unwrap_or_default()) }
+    }
+
+    fn is_full(&self) -> bool {
+        self.length == self.buffer.len()
+    }
+
+    fn length(&self) -> usize {
+        self.length
+    }
+
+    fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn get(&self, index: usize) -> Result<&CHARACTER, FixedStringError> {
+        self.buffer
+            .get(index)
+            .ok_or(FixedStringError::InvalidIndex {
+                index,
+                length: self.buffer.len(),
+            })
+    }
+}
+
+impl FixedStringRef for FixedStrMut<'_> {
+    fn clear(&mut self) {
+        self.length = 0;
+    }
+
+    fn truncate(&mut self, length: usize) -> Result<(), FixedStringError> {
+        if length >= self.length {
+            return Ok(());
+        }
+
+        if !self.as_str().is_char_boundary(length) {
+            return Err(FixedStringError::InvalidIndex {
+                index: length,
+                length: self.length,
+            });
+        }
+
+        self.length = length;
+        Ok(())
+    }
+
+    fn assign(&mut self, string: &str) -> Result<(), FixedStringError> {
+        if self.length != 0 {
+            return Err(FixedStringError::AlreadyAssigned);
+        }
+
+        self.push(string)
+    }
+
+    fn push(&mut self, string: &str) -> Result<(), FixedStringError> {
+        let end = self.length.saturating_add(string.len());
+        let Some(dest) = self.buffer.get_mut(self.length..end) else {
+            return Err(FixedStringError::Overflow {
+                requested: string.len(),
+                available: self.buffer.len().saturating_sub(self.length),
+            });
+        };
+
+        dest.copy_from_slice(string.as_bytes());
+        self.length = end;
+
+        Ok(())
+    }
+
+    fn push_char(&mut self, character: char) -> Result<(), FixedStringError> {
+        let mut encoded = [0; 4];
+        self.push(character.encode_utf8(&mut encoded))
+    }
+
+    fn get_mut(&mut self, index: usize) -> Result<&mut CHARACTER, FixedStringError> {
+        let length = self.buffer.len();
+        self.buffer
+            .get_mut(index)
+            .ok_or(FixedStringError::InvalidIndex { index, length })
+    }
+}
+
+impl fmt::Debug for FixedStrMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !f.alternate() {
+            return write!(f, "{}", self.as_str());
+        }
+
+        write!(
+            f,
+            "FixedStrMut {{ capacity: {}, len: {}, ",
+            self.capacity(),
+            self.length()
+        )?;
+        write_escaped(self.as_str().chars(), f)?;
+        f.write_str(" }")
+    }
+}
+
+impl AsRef<str> for FixedStrMut<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FixedStrMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl fmt::Write for FixedStrMut<'_> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push(string).map_err(|_| fmt::Error)
+    }
+}
@@ -0,0 +1,109 @@
+use core::{
+    char,
+    convert::{From, TryFrom},
+    iter::Iterator,
+    result::Result::{self, Err, Ok},
+};
+
+use crate::{
+    FixedString, FixedStringError, FixedStringView, FixedWideString, LengthType, Utf8, Utf16,
+};
+
+impl<const N: usize> FixedString<N, Utf16> {
+    /// Creates a new `FixedWideString` from UTF-16 code units, validating
+    /// that they are well formed
+    pub fn from_utf16(units: &[u16]) -> Result<Self, FixedStringError> {
+        if units.len() > N {
+            return Err(FixedStringError::Overflow {
+                requested: units.len(),
+                available: N,
+            });
+        }
+
+        if char::decode_utf16(units.iter().copied()).any(|character| character.is_err()) {
+            return Err(FixedStringError::InvalidUtf16);
+        }
+
+        let mut res = Self::new();
+        res.write_units(0, units)?;
+        res.length = units.len();
+        Ok(res)
+    }
+
+    /// Write the content as UTF-16LE bytes into `out`, returning the number of
+    /// bytes written
+    pub fn write_le_bytes(&self, out: &mut [u8]) -> Result<usize, FixedStringError> {
+        let requested = self.length.saturating_mul(2);
+        if requested > out.len() {
+            return Err(FixedStringError::Overflow {
+                requested,
+                available: out.len(),
+            });
+        }
+
+        for (unit, bytes) in self.as_units().iter().zip(out.chunks_exact_mut(2)) {
+            bytes.copy_from_slice(&unit.to_le_bytes());
+        }
+
+        Ok(requested)
+    }
+}
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Appends UTF-16 code units, e.g. from a USB string descriptor, converted
+    /// to UTF-8. Nothing is appended if they aren't well formed or don't fit.
+    pub fn extend_from_utf16(&mut self, units: &[u16]) -> Result<(), FixedStringError> {
+        let requested = char::decode_utf16(units.iter().copied())
+            .map(|character| character.map(char::len_utf8))
+            .sum::<Result<usize, _>>()
+            .map_err(|_| FixedStringError::InvalidUtf16)?;
+        self.extend_from_chars(
+            requested,
+            char::decode_utf16(units.iter().copied())
+                .map(|character| character.unwrap_or_default()),
+        )
+    }
+
+    /// Appends UTF-16 code units converted to UTF-8, replacing unpaired
+    /// surrogates with `U+FFFD`. Nothing is appended if they don't fit.
+    pub fn extend_from_utf16_lossy(&mut self, units: &[u16]) -> Result<(), FixedStringError> {
+        let characters = char::decode_utf16(units.iter().copied())
+            .map(|character| character.unwrap_or(char::REPLACEMENT_CHARACTER));
+        let requested = characters.clone().map(char::len_utf8).sum();
+        self.extend_from_chars(requested, characters)
+    }
+
+    /// Appends `characters` taking up `requested` bytes, if they fit
+    fn extend_from_chars(
+        &mut self,
+        requested: usize,
+        characters: impl Iterator<Item = char>,
+    ) -> Result<(), FixedStringError> {
+        self.grown_length(requested)?;
+
+        let mut encoded = [0; 4];
+        for character in characters {
+            self.push_units(character.encode_utf8(&mut encoded).as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+/// A UTF-8 string never needs more UTF-16 code units than bytes, so the
+/// conversion always fits
+impl<const N: usize> From<&FixedString<N>> for FixedWideString<N> {
+    fn from(fixed_string: &FixedString<N>) -> Self {
+        let mut res = Self::new();
+        let _ = res.push(fixed_string.as_str());
+        res
+    }
+}
+
+/// Convert to UTF-8, failing if the encoded string doesn't fit
+impl<const N: usize, const M: usize> TryFrom<&FixedWideString<N>> for FixedString<M> {
+    type Error = FixedStringError;
+
+    fn try_from(wide_string: &FixedWideString<N>) -> Result<Self, Self::Error> {
+        wide_string.transcode()
+    }
+}
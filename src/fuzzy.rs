@@ -0,0 +1,57 @@
+use core::{convert::TryFrom, iter::Iterator, option::Option};
+
+use crate::{FixedString, LengthType, TextEncoding};
+
+impl<const N: usize, E: TextEncoding, L: LengthType> FixedString<N, E, L> {
+    /// Returns the Levenshtein distance to `other`, the number of inserted,
+    /// removed or replaced characters needed to turn one into the other. The
+    /// workspace is a `[u16; N]` on the stack, so distances saturate at
+    /// `u16::MAX`.
+    pub fn edit_distance(&self, other: &str) -> usize {
+        // row[j] is the distance between the first j + 1 characters of self
+        // and the part of other handled so far
+        let mut row = [0u16; N];
+        let row = row.get_mut(..self.char_count()).unwrap_or_default();
+        for (j, distance) in row.iter_mut().enumerate() {
+            *distance = saturate(j.saturating_add(1));
+        }
+
+        for (i, other_character) in other.chars().enumerate() {
+            let mut diagonal = saturate(i);
+            let mut left = saturate(i.saturating_add(1));
+            for (distance, character) in row.iter_mut().zip(self.chars()) {
+                let replaced = diagonal.saturating_add((character != other_character) as u16);
+                diagonal = *distance;
+                *distance = replaced
+                    .min(distance.saturating_add(1))
+                    .min(left.saturating_add(1));
+                left = *distance;
+            }
+        }
+
+        match row.last() {
+            Some(&distance) => usize::from(distance),
+            None => other.chars().count(),
+        }
+    }
+
+    /// Returns the candidate with the smallest edit distance, if it's at most
+    /// `max_distance`, e.g. to suggest a command for a mistyped one
+    pub fn closest_match<'a>(
+        &self,
+        candidates: &[&'a str],
+        max_distance: usize,
+    ) -> Option<&'a str> {
+        candidates
+            .iter()
+            .map(|candidate| (self.edit_distance(candidate), *candidate))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, candidate)| candidate)
+    }
+}
+
+/// Converts a distance to the `u16` workspace, saturating at `u16::MAX`
+fn saturate(distance: usize) -> u16 {
+    u16::try_from(distance).unwrap_or(u16::MAX)
+}
@@ -0,0 +1,52 @@
+use core::{
+    iter::Iterator,
+    option::Option::{None, Some},
+};
+
+/// Returns `true` if all of `text` matches `pattern`, where `*` matches any
+/// run of characters and `?` a single character
+pub(crate) fn matches_glob(text: &str, pattern: &str) -> bool {
+    let (mut text_index, mut pattern_index) = (0, 0);
+    // Positions after the last `*` and the text it was matched against, to
+    // retry with the star covering one more character
+    let mut backtrack = None;
+
+    while text_index < text.len() {
+        let character = first_char(text, text_index).unwrap_or_default();
+        match first_char(pattern, pattern_index) {
+            Some('*') => {
+                pattern_index = pattern_index.saturating_add(1);
+                backtrack = Some((pattern_index, text_index));
+                continue;
+            }
+            Some(expected) if expected == '?' || expected == character => {
+                pattern_index = pattern_index.saturating_add(expected.len_utf8());
+                text_index = text_index.saturating_add(character.len_utf8());
+                continue;
+            }
+            _ => {}
+        }
+
+        match backtrack {
+            Some((star_pattern, star_text)) => {
+                let skipped = first_char(text, star_text).unwrap_or_default();
+                let star_text = star_text.saturating_add(skipped.len_utf8());
+                backtrack = Some((star_pattern, star_text));
+                pattern_index = star_pattern;
+                text_index = star_text;
+            }
+            None => return false,
+        }
+    }
+
+    pattern
+        .get(pattern_index..)
+        .unwrap_or_default()
+        .chars()
+        .all(|character| character == '*')
+}
+
+/// Returns the character starting at byte `index` of `text`
+fn first_char(text: &str, index: usize) -> Option<char> {
+    text.get(index..)?.chars().next()
+}
@@ -0,0 +1,43 @@
+use core::{
+    convert::{From, TryFrom},
+    result::Result::{self, Err, Ok},
+    str,
+};
+
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+impl<const N: usize> From<FixedString<N>> for heapless::String<N> {
+    fn from(fixed_string: FixedString<N>) -> Self {
+        let mut string = heapless::String::new();
+        // Both have a capacity of `N`, so this can't overflow
+        let _ = string.push_str(fixed_string.as_str());
+        string
+    }
+}
+
+impl<const N: usize> From<heapless::String<N>> for FixedString<N> {
+    fn from(string: heapless::String<N>) -> Self {
+        Self::from_valid_bytes(string.as_bytes())
+    }
+}
+
+impl<const N: usize> From<FixedString<N>> for heapless::Vec<u8, N> {
+    fn from(fixed_string: FixedString<N>) -> Self {
+        let mut vec = heapless::Vec::new();
+        // Both have a capacity of `N`, so this can't overflow
+        let _ = vec.extend_from_slice(fixed_string.as_str().as_bytes());
+        vec
+    }
+}
+
+/// Create a `FixedString` from a byte vector, validating that it's UTF-8
+impl<const N: usize> TryFrom<heapless::Vec<u8, N>> for FixedString<N> {
+    type Error = FixedStringError;
+
+    fn try_from(vec: heapless::Vec<u8, N>) -> Result<Self, Self::Error> {
+        match str::from_utf8(&vec) {
+            Ok(string) => Ok(Self::from_valid_bytes(string.as_bytes())),
+            Err(_) => Err(FixedStringError::InvalidUtf8),
+        }
+    }
+}
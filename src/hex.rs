@@ -0,0 +1,172 @@
+use core::{
+    fmt,
+    iter::Iterator,
+    option::Option::{None, Some},
+    result::Result::{self, Err, Ok},
+    str, write,
+};
+
+use crate::{Encoding, FixedString, FixedStringError, LengthType, TextEncoding, Utf8};
+
+const LOWER_DIGITS: &[u8; 16] = b"0123456789abcdef";
+pub(crate) const UPPER_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Case of the letters in hex output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexCase {
+    Lower,
+    Upper,
+}
+
+/// Returns the value of a hex digit in either case
+pub(crate) fn hex_value(digit: u8) -> Option<u8> {
+    (digit as char).to_digit(16).map(|value| value as u8)
+}
+
+/// Returns the two hex digits of `byte`, taken from `digits`
+pub(crate) fn hex_pair(byte: u8, digits: &[u8; 16]) -> [u8; 2] {
+    let digit = |nibble: u8| digits.get(usize::from(nibble)).copied().unwrap_or_default();
+    [digit(byte >> 4), digit(byte & 0xf)]
+}
+
+impl<const N: usize, E: TextEncoding, L: LengthType> FixedString<N, E, L> {
+    /// Creates a new `FixedString` with `bytes` encoded as hex
+    pub fn from_hex_bytes(bytes: &[u8], case: HexCase) -> Result<Self, FixedStringError> {
+        let mut res = Self::new();
+        res.push_hex(bytes, case)?;
+        Ok(res)
+    }
+
+    /// Appends `bytes` encoded as two hex digits each, if they fit
+    pub fn push_hex(&mut self, bytes: &[u8], case: HexCase) -> Result<(), FixedStringError> {
+        self.grown_length(bytes.len().saturating_mul(2))?;
+
+        let digits = match case {
+            HexCase::Lower => LOWER_DIGITS,
+            HexCase::Upper => UPPER_DIGITS,
+        };
+        let mut encoded = [0; 64];
+        for chunk in bytes.chunks(encoded.len() / 2) {
+            for (byte, pair) in chunk.iter().zip(encoded.as_chunks_mut().0) {
+                *pair = hex_pair(*byte, digits);
+            }
+            let encoded = encoded.get(..chunk.len().saturating_mul(2));
+            self.push(unsafe { str::from_utf8_unchecked(encoded.unwrap_or_default()) })?;
+        }
+
+        Ok(())
+    }
+
+    /// Appends `bytes` encoded as two hex digits each, separated by
+    /// `delimiter`, e.g. `AA:BB:CC:DD:EE:FF` for a MAC address, if they fit
+    pub fn push_bytes_hex_delimited(
+        &mut self,
+        bytes: &[u8],
+        delimiter: &str,
+        case: HexCase,
+    ) -> Result<(), FixedStringError> {
+        let delimiters = bytes.len().saturating_sub(1);
+        self.grown_length(
+            bytes
+                .len()
+                .saturating_mul(2)
+                .saturating_add(delimiters.saturating_mul(E::encoded_len(delimiter))),
+        )?;
+
+        for (i, byte) in bytes.iter().enumerate() {
+            if i > 0 {
+                self.push(delimiter)?;
+            }
+            self.push_hex(&[*byte], case)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Decodes hex content of either case into `out`, returning the number of
+    /// bytes written
+    pub fn decode_hex_into(&self, out: &mut [u8]) -> Result<usize, FixedStringError> {
+        let digits = self.as_units();
+        if !digits.len().is_multiple_of(2) {
+            return Err(FixedStringError::InvalidHex {
+                index: digits.len(),
+            });
+        }
+
+        let decoded_len = digits.len() / 2;
+        if decoded_len > out.len() {
+            return Err(FixedStringError::Overflow {
+                requested: decoded_len,
+                available: out.len(),
+            });
+        }
+
+        for (i, ([high, low], out)) in digits.as_chunks().0.iter().zip(out).enumerate() {
+            let index = i.saturating_mul(2);
+            let high = hex_value(*high).ok_or(FixedStringError::InvalidHex { index })?;
+            let low = hex_value(*low).ok_or(FixedStringError::InvalidHex {
+                index: index.saturating_add(1),
+            })?;
+            *out = (high << 4) | low;
+        }
+
+        Ok(decoded_len)
+    }
+}
+
+/// Bytes shown per line of a `HexDump`
+const HEXDUMP_WIDTH: usize = 16;
+
+/// Displays bytes like `hexdump -C`, as lines of an offset, 16 bytes in hex
+/// and the printable ASCII characters among them, e.g.
+/// `00000000  48 65 6c 6c 6f 0a                                 |Hello.|`
+#[derive(Debug, Clone, Copy)]
+pub struct HexDump<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> HexDump<'a> {
+    /// Creates a new `HexDump` of `bytes`
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+
+impl fmt::Display for HexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (line, chunk) in self.bytes.chunks(HEXDUMP_WIDTH).enumerate() {
+            if line > 0 {
+                f.write_str("\n")?;
+            }
+
+            write!(f, "{:08x} ", line.saturating_mul(HEXDUMP_WIDTH))?;
+            for i in 0..HEXDUMP_WIDTH {
+                if i % 8 == 0 {
+                    f.write_str(" ")?;
+                }
+                match chunk.get(i) {
+                    Some(byte) => write!(f, "{:02x} ", byte)?,
+                    None => f.write_str("   ")?,
+                }
+            }
+
+            f.write_str(" |")?;
+            for byte in chunk {
+                let printable = byte.is_ascii_graphic() || *byte == b' ';
+                write!(f, "{}", if printable { *byte as char } else { '.' })?;
+            }
+            f.write_str("|")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const N: usize, E: Encoding<Unit = u8>, L: LengthType> FixedString<N, E, L> {
+    /// Returns an adapter displaying the content as a hex dump, see `HexDump`
+    pub fn hexdump(&self) -> HexDump<'_> {
+        HexDump::new(self.as_units())
+    }
+}
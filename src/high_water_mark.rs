@@ -0,0 +1,125 @@
+use core::{convert::AsRef, fmt, result::Result};
+
+use crate::{CHARACTER, FixedStringError, FixedStringRef, FixedStringView};
+
+/// Wraps a fixed string and records the longest length it ever reached, so
+/// capacities can be tuned from measurements. It can be used wherever a
+/// `FixedStringRef` is expected.
+#[derive(Clone, Default)]
+pub struct HighWaterMark<W: FixedStringRef> {
+    inner: W,
+    high_water_mark: usize,
+}
+
+impl<W: FixedStringRef> HighWaterMark<W> {
+    /// Creates a new `HighWaterMark` starting at the current length of `inner`
+    pub fn new(inner: W) -> Self {
+        let high_water_mark = inner.length();
+        Self {
+            inner,
+            high_water_mark,
+        }
+    }
+
+    /// Returns the longest length reached
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water_mark
+    }
+
+    /// Restarts recording from the current length
+    pub fn reset_high_water_mark(&mut self) {
+        self.high_water_mark = self.inner.length();
+    }
+
+    /// Returns a reference to the wrapped string
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns the wrapped string
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Records the current length after a change
+    fn record<T>(&mut self, result: T) -> T {
+        self.high_water_mark = self.high_water_mark.max(self.inner.length());
+        result
+    }
+}
+
+impl<W: FixedStringRef> FixedStringView for HighWaterMark<W> {
+    fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+
+    fn length(&self) -> usize {
+        self.inner.length()
+    }
+
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    fn get(&self, index: usize) -> Result<&CHARACTER, FixedStringError> {
+        self.inner.get(index)
+    }
+}
+
+impl<W: FixedStringRef> FixedStringRef for HighWaterMark<W> {
+    fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    fn truncate(&mut self, length: usize) -> Result<(), FixedStringError> {
+        self.inner.truncate(length)
+    }
+
+    fn assign(&mut self, string: &str) -> Result<(), FixedStringError> {
+        let assigned = self.inner.assign(string);
+        self.record(assigned)
+    }
+
+    fn push(&mut self, string: &str) -> Result<(), FixedStringError> {
+        let pushed = self.inner.push(string);
+        self.record(pushed)
+    }
+
+    fn push_char(&mut self, character: char) -> Result<(), FixedStringError> {
+        let pushed = self.inner.push_char(character);
+        self.record(pushed)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Result<&mut CHARACTER, FixedStringError> {
+        self.inner.get_mut(index)
+    }
+}
+
+impl<W: FixedStringRef> fmt::Debug for HighWaterMark<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<W: FixedStringRef> fmt::Display for HighWaterMark<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl<W: FixedStringRef> fmt::Write for HighWaterMark<W> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        let written = self.inner.write_str(string);
+        self.record(written)
+    }
+}
+
+impl<W: FixedStringRef> AsRef<str> for HighWaterMark<W> {
+    fn as_ref(&self) -> &str {
+        self.inner.as_str()
+    }
+}
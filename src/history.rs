@@ -0,0 +1,118 @@
+use core::{
+    default::Default,
+    option::Option::{self, None, Some},
+    result::Result::{self, Ok},
+};
+
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+/// Stores the last `DEPTH` input lines, e.g. of a command line interface, and
+/// navigates through them from the newest to the oldest. Empty lines and
+/// lines repeating the newest entry aren't stored.
+#[derive(Debug, Clone)]
+pub struct History<const N: usize, const DEPTH: usize> {
+    entries: [FixedString<N>; DEPTH],
+    /// Index the next entry is written to
+    head: usize,
+    count: usize,
+    /// Entry shown during navigation, counted backwards from the newest
+    position: Option<usize>,
+}
+
+impl<const N: usize, const DEPTH: usize> History<N, DEPTH> {
+    /// Creates a new empty `History`
+    pub const fn new() -> Self {
+        Self {
+            entries: [FixedString::new(); DEPTH],
+            head: 0,
+            count: 0,
+            position: None,
+        }
+    }
+
+    /// Returns the number of stored lines
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no lines are stored
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Stores a line as the newest entry, dropping the oldest one if full.
+    /// Ends any navigation.
+    pub fn push(&mut self, line: &str) -> Result<(), FixedStringError> {
+        self.position = None;
+        if DEPTH == 0 || line.is_empty() || self.get(0) == Some(line) {
+            return Ok(());
+        }
+
+        if let Some(entry) = self.entries.get_mut(self.head) {
+            *entry = FixedString::new_with(line)?;
+        }
+        self.head = self
+            .head
+            .saturating_add(1)
+            .checked_rem(DEPTH)
+            .unwrap_or_default();
+        self.count = self.count.saturating_add(1).min(DEPTH);
+
+        Ok(())
+    }
+
+    /// Returns a stored line, where `0` is the newest
+    pub fn get(&self, age: usize) -> Option<&str> {
+        if age >= self.count {
+            return None;
+        }
+
+        let index = self
+            .head
+            .checked_add(DEPTH)?
+            .checked_sub(age.checked_add(1)?)?
+            .checked_rem(DEPTH)?;
+        self.entries.get(index).map(FixedString::as_str)
+    }
+
+    /// Steps to the next older line, staying at the oldest one
+    pub fn up(&mut self) -> Option<&str> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let position = match self.position {
+            Some(position) => position.saturating_add(1).min(self.count.saturating_sub(1)),
+            None => 0,
+        };
+        self.position = Some(position);
+        self.get(position)
+    }
+
+    /// Steps to the next newer line. Returns `None` when stepping past the
+    /// newest line, back to the line being typed.
+    pub fn down(&mut self) -> Option<&str> {
+        match self.position {
+            Some(0) | None => {
+                self.position = None;
+                None
+            }
+            Some(position) => {
+                let position = position.saturating_sub(1);
+                self.position = Some(position);
+                self.get(position)
+            }
+        }
+    }
+
+    /// Ends navigation, so the next `up` starts from the newest line again
+    pub fn reset(&mut self) {
+        self.position = None;
+    }
+}
+
+impl<const N: usize, const DEPTH: usize> Default for History<N, DEPTH> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
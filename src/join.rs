@@ -0,0 +1,57 @@
+use core::{
+    convert::AsRef,
+    iter::IntoIterator,
+    result::Result::{self, Err, Ok},
+};
+
+use crate::{FixedString, FixedStringError, LengthType, Utf8};
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Creates a fixed string from `parts` with `separator` between them, e.g.
+    /// a comma separated list of sensor values
+    pub fn join<S: AsRef<str>>(
+        parts: impl IntoIterator<Item = S>,
+        separator: &str,
+    ) -> Result<Self, FixedStringError> {
+        let mut fixed_string = Self::new();
+        fixed_string.push_joined(parts, separator)?;
+        Ok(fixed_string)
+    }
+
+    /// Appends `parts` with `separator` between them. Nothing is appended if
+    /// the whole list doesn't fit.
+    pub fn push_joined<S: AsRef<str>>(
+        &mut self,
+        parts: impl IntoIterator<Item = S>,
+        separator: &str,
+    ) -> Result<(), FixedStringError> {
+        let length = self.length;
+        let mut requested = 0usize;
+        let mut overflowed = false;
+
+        for (index, part) in parts.into_iter().enumerate() {
+            let part = part.as_ref();
+            let separator = if index == 0 { "" } else { separator };
+            requested = requested
+                .saturating_add(separator.len())
+                .saturating_add(part.len());
+
+            // Keep counting after an overflow to report the full size
+            if !overflowed
+                && (self.push_units(separator.as_bytes()).is_err()
+                    || self.push_units(part.as_bytes()).is_err())
+            {
+                overflowed = true;
+            }
+        }
+
+        if overflowed {
+            self.length = length;
+            return Err(FixedStringError::Overflow {
+                requested,
+                available: N.saturating_sub(length.to_usize()),
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use core::fmt;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Describes the integer a `FixedString` stores its length in. A smaller type
+/// than the default `usize` shrinks strings with small capacities, e.g. a
+/// `FixedString<8, Utf8, u8>` takes up 9 bytes instead of 16.
+pub trait LengthType: sealed::Sealed + Copy + Eq + fmt::Debug {
+    /// A length of zero
+    const ZERO: Self;
+    /// The largest capacity that can be represented
+    const MAX: usize;
+    /// Converts the length to a `usize`
+    fn to_usize(self) -> usize;
+    /// Converts a `usize` of at most `MAX` to a length
+    fn from_usize(value: usize) -> Self;
+}
+
+macro_rules! impl_length_type {
+    ($($length:ty),*) => {
+        $(
+            impl sealed::Sealed for $length {}
+
+            impl LengthType for $length {
+                const ZERO: Self = 0;
+                const MAX: usize = if (<$length>::MAX as u128) < usize::MAX as u128 {
+                    <$length>::MAX as usize
+                } else {
+                    usize::MAX
+                };
+
+                fn to_usize(self) -> usize {
+                    self as usize
+                }
+
+                fn from_usize(value: usize) -> Self {
+                    value as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_length_type!(u8, u16, u32, usize);
@@ -1,117 +1,1034 @@
 #![no_std]
+#![cfg_attr(
+    feature = "panic-free",
+    deny(
+        clippy::panic,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::unreachable,
+        clippy::todo,
+        clippy::unimplemented,
+        clippy::indexing_slicing,
+        clippy::arithmetic_side_effects,
+        clippy::string_slice
+    )
+)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(test)]
+#[cfg_attr(
+    feature = "panic-free",
+    allow(
+        clippy::unwrap_used,
+        clippy::indexing_slicing,
+        clippy::arithmetic_side_effects,
+        clippy::string_slice
+    )
+)]
 mod tests;
 
+mod base32;
+mod c_fixed_string;
+mod case;
+mod chain;
+mod chunked;
+mod complete;
+mod csv;
+mod cursor;
+mod dma_fixed_string;
+#[cfg(target_has_atomic = "8")]
+mod double_buffer;
+mod duration;
+mod encoding;
+mod escape;
+mod field;
+mod fixed_bytes;
+mod fixed_str;
+mod fixed_str_mut;
+mod fixed_wide_string;
+mod fuzzy;
+mod glob;
+mod hex;
+mod high_water_mark;
+mod history;
+mod join;
+mod length_type;
+mod line_accumulator;
+mod line_editor;
+mod natural;
+mod newline;
+mod nmea;
+mod numeric;
+mod overflow_stats;
+mod parse;
+mod pattern;
+mod percent;
+#[cfg(target_has_atomic = "8")]
+mod pool;
+mod printf;
+mod query;
+mod resize;
+mod ring_buffer;
+mod sanitize;
+mod scan;
+mod search;
+mod split;
+mod template;
+mod tokenize;
+mod utf8_decoder;
+
+#[cfg(feature = "alloc")]
+mod alloc_impl;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+#[cfg(feature = "arrayvec")]
+mod arrayvec_impl;
+#[cfg(feature = "critical-section")]
+mod critical_section_impl;
+#[cfg(feature = "embedded-io")]
+mod embedded_io_impl;
+#[cfg(feature = "heapless")]
+mod heapless_impl;
+#[cfg(feature = "log")]
+mod log_impl;
+#[cfg(feature = "memchr")]
+mod memchr_impl;
+#[cfg(feature = "postcard")]
+mod postcard_impl;
+#[cfg(feature = "regex-lite")]
+mod regex_lite;
+#[cfg(feature = "rkyv")]
+mod rkyv_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "std")]
+mod std_impl;
+#[cfg(feature = "zerocopy")]
+mod zerocopy_impl;
+#[cfg(feature = "zeroize")]
+mod zeroize_impl;
+
+pub use base32::Base32Alphabet;
+pub use c_fixed_string::CFixedString;
+pub use chain::FixedStringChain;
+pub use chunked::ChunkedWriter;
+pub use complete::common_prefix;
+#[cfg(feature = "critical-section")]
+pub use critical_section_impl::StaticFixedString;
+pub use csv::{CsvField, CsvFields};
+pub use cursor::Cursor;
+pub use dma_fixed_string::{DMA_ALIGNMENT, DmaFixedString};
+#[cfg(target_has_atomic = "8")]
+pub use double_buffer::{DoubleBufferReader, DoubleBufferWriter, DoubleBufferedFixedString};
+pub use encoding::{Bytes, Encoding, TextEncoding, Utf8, Utf16, Utf32};
+pub use fixed_bytes::FixedBytes;
+pub use fixed_str::FixedStr;
+pub use fixed_str_mut::FixedStrMut;
+pub use hex::{HexCase, HexDump};
+pub use high_water_mark::HighWaterMark;
+pub use history::History;
+pub use length_type::LengthType;
+pub use line_accumulator::{LineAccumulator, Truncation};
+pub use line_editor::LineEditor;
+#[cfg(feature = "log")]
+pub use log_impl::FixedStringLogger;
+pub use newline::LineEnding;
+pub use numeric::{FloatFormat, Rounding};
+pub use overflow_stats::OverflowStatsWriter;
+pub use parse::FromStrRadix;
+pub use pattern::{MatchIndices, Matches, Pattern, Split};
+pub use percent::PercentCharset;
+#[cfg(target_has_atomic = "8")]
+pub use pool::{FixedStringPool, PooledFixedString};
+pub use printf::PrintfArg;
+pub use query::QueryPairs;
+#[cfg(feature = "regex-lite")]
+pub use regex_lite::Regex;
+pub use ring_buffer::FixedStringRingBuffer;
+#[cfg(feature = "rkyv")]
+pub use rkyv_impl::ArchivedFixedString;
+pub use scan::ScanValue;
+#[cfg(feature = "serde")]
+pub use serde_impl::deserialize_truncating;
+pub use utf8_decoder::Utf8Decoder;
+#[cfg(feature = "zerocopy")]
+pub use zerocopy_impl::RawFixedString;
+#[cfg(feature = "zeroize")]
+pub use zeroize_impl::SecretFixedString;
+
+/// The string traits, for `use fixed_string::prelude::*;`
+pub mod prelude {
+    pub use crate::{FixedStringRef, FixedStringSearch, FixedStringView};
+}
+
 use core::{
+    any,
     clone::Clone,
-    cmp::{Ord, PartialEq, min},
+    cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd},
+    convert::AsRef,
     default::Default,
-    fmt,
+    error,
+    ffi::CStr,
+    fmt, hint,
     iter::Iterator,
-    ops::{Index, IndexMut},
+    mem,
+    ops::Range,
     option::Option::{self, None, Some},
-    panic,
+    ptr,
     result::Result::{self, Err, Ok},
-    str, write,
+    slice,
+    str::{self, FromStr},
+    sync::atomic,
+    write,
+};
+use core::{
+    ops::{Index, IndexMut},
+    panic,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FixedStringError {
     AlreadyAssigned,
-    Overflow,
-    InvalidIndex,
+    /// The operation needed more bytes than were available in the buffer
+    Overflow {
+        requested: usize,
+        available: usize,
+    },
+    /// The index was outside the content of the string
+    InvalidIndex {
+        index: usize,
+        length: usize,
+    },
     FormatError,
+    InvalidUtf8,
+    /// The string contains a NUL byte where none is allowed
+    InteriorNul {
+        index: usize,
+    },
+    InvalidUtf16,
+    /// The content couldn't be parsed into the requested type
+    ParseError,
+    /// The content isn't an even number of hex digits
+    InvalidHex {
+        index: usize,
+    },
+    /// The content isn't valid Base32
+    InvalidBase32 {
+        index: usize,
+    },
+    /// The string is already borrowed, e.g. by an enclosing `with`
+    Busy,
+}
+
+impl fmt::Display for FixedStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixedStringError::AlreadyAssigned => write!(f, "string is already assigned"),
+            FixedStringError::Overflow {
+                requested,
+                available,
+            } => write!(
+                f,
+                "string capacity exceeded, {} bytes requested but only {} available",
+                requested, available
+            ),
+            FixedStringError::InvalidIndex { index, length } => {
+                write!(f, "index {} out of bounds for length {}", index, length)
+            }
+            FixedStringError::FormatError => write!(f, "formatting failed"),
+            FixedStringError::InvalidUtf8 => write!(f, "invalid UTF-8"),
+            FixedStringError::InteriorNul { index } => {
+                write!(f, "interior NUL byte at index {}", index)
+            }
+            FixedStringError::InvalidUtf16 => write!(f, "invalid UTF-16"),
+            FixedStringError::ParseError => write!(f, "content couldn't be parsed"),
+            FixedStringError::InvalidHex { index } => {
+                write!(f, "invalid hex digit at index {}", index)
+            }
+            FixedStringError::InvalidBase32 { index } => {
+                write!(f, "invalid Base32 at index {}", index)
+            }
+            FixedStringError::Busy => write!(f, "string is already borrowed"),
+        }
+    }
 }
 
-pub trait FixedStringRef: fmt::Debug + fmt::Display + fmt::Write {
+impl error::Error for FixedStringError {}
+
+/// Read-only access to a UTF-8 fixed string, so inspecting a string doesn't
+/// require `&mut`. These methods used to be part of `FixedStringRef`, so code
+/// calling them needs this trait in scope too, e.g. through the `prelude`.
+/// It isn't called `FixedStr` as that name is taken by the borrowed string.
+pub trait FixedStringView: fmt::Debug + fmt::Display {
     /// Returns a `&str` representation of the `String`.
-    fn as_str<'a>(&'a self) -> &'a str;
+    fn as_str(&self) -> &str;
+    /// Returns the content as UTF-8 encoded bytes
+    fn as_bytes(&self) -> &[CHARACTER] {
+        self.as_str().as_bytes()
+    }
     /// Returns `true` if the `FixedString` is full.
     fn is_full(&self) -> bool;
     /// Returns the current length of the `FixedString`
     fn length(&self) -> usize;
     /// Returns the total capacity of the `FixedString`
     fn capacity(&self) -> usize;
+    /// Returns the number of characters, unlike `length` which counts bytes
+    fn char_count(&self) -> usize {
+        self.as_str().chars().count()
+    }
+    /// Returns the number of bytes that can still be appended
+    fn remaining_capacity(&self) -> usize {
+        self.capacity().saturating_sub(self.length())
+    }
+    /// Returns `true` if the content starts with `prefix`
+    fn starts_with(&self, prefix: &str) -> bool {
+        self.as_str().starts_with(prefix)
+    }
+    /// Returns `true` if the content starts with `chunks` put together, e.g.
+    /// to check that the data received so far is the beginning of an
+    /// expected response, without first copying it into another buffer
+    fn starts_with_chunks(&self, chunks: &[&[u8]]) -> bool {
+        let mut rest = self.as_bytes();
+        for chunk in chunks {
+            match rest.strip_prefix(*chunk) {
+                Some(stripped) => rest = stripped,
+                None => return false,
+            }
+        }
+        true
+    }
+    /// Returns `true` if the content is exactly the bytes from `bytes`, e.g.
+    /// as they are read from a UART, without first collecting them into
+    /// another buffer
+    fn eq_bytes_iter(&self, bytes: impl IntoIterator<Item = u8>) -> bool
+    where
+        Self: Sized,
+    {
+        self.as_bytes().iter().copied().eq(bytes)
+    }
+    /// Returns the byte index of the first occurrence of `pattern`
+    fn find(&self, pattern: &str) -> Option<usize> {
+        search::find(self.as_str(), pattern)
+    }
+    /// Returns the byte index of the first occurrence of `pattern`, comparing
+    /// ASCII letters case-insensitively
+    fn find_ignore_ascii_case(&self, pattern: &str) -> Option<usize> {
+        search::find_ignore_ascii_case(self.as_str(), pattern)
+    }
+    /// Returns `true` if byte `index` is the start of a character or the end of
+    /// the content
+    fn is_char_boundary(&self, index: usize) -> bool {
+        self.as_str().is_char_boundary(index)
+    }
+    /// Returns the closest character boundary at or before byte `index`,
+    /// at most the length
+    fn floor_char_boundary(&self, index: usize) -> usize {
+        self.as_str().floor_char_boundary(index)
+    }
+    /// Returns the closest character boundary at or after byte `index`, at
+    /// most the length
+    fn ceil_char_boundary(&self, index: usize) -> usize {
+        self.as_str().ceil_char_boundary(index)
+    }
+    /// Returns `true` if the whole content matches `pattern`, where `*`
+    /// matches any run of characters and `?` a single character
+    fn matches_glob(&self, pattern: &str) -> bool {
+        glob::matches_glob(self.as_str(), pattern)
+    }
+    /// Returns what can be appended to complete the content without
+    /// ambiguity, given the `candidates` starting with it, e.g. for tab
+    /// completion. `None` if no candidate does.
+    fn complete_from<'a>(&self, candidates: &[&'a str]) -> Option<&'a str> {
+        complete::complete_from(self.as_str(), candidates)
+    }
+    /// Extracts fields from content laid out like `format`, the inverse of
+    /// `push_printf`, e.g. `V=%u,T=%d`. Each `%d`, `%i`, `%u`, `%x`, `%c` and
+    /// `%s` writes the next of `values`, returning how many were written. A
+    /// `%s` runs until the literal text following it in `format`. Content
+    /// that doesn't match all of `format`, or more fields than `values`, is
+    /// rejected with a `ParseError`.
+    fn scan<'a>(
+        &'a self,
+        format: &str,
+        values: &mut [ScanValue<'a>],
+    ) -> Result<usize, FixedStringError> {
+        scan::scan(self.as_str(), format, values)
+    }
+    /// Returns the trimmed content of the byte columns `columns` of a fixed
+    /// column record. Columns past the end of a shorter record are empty. A
+    /// column boundary inside a character is rejected with an `InvalidIndex`.
+    fn field(&self, columns: Range<usize>) -> Result<&str, FixedStringError> {
+        field::field(self.as_str(), columns)
+    }
+    /// Returns the trimmed content of each `(start, length)` column of a
+    /// fixed column record, see `field`
+    fn fields<const K: usize>(
+        &self,
+        columns: &[(usize, usize); K],
+    ) -> Result<[&str; K], FixedStringError>
+    where
+        Self: Sized,
+    {
+        let mut fields = [""; K];
+        for (field, (start, length)) in fields.iter_mut().zip(columns) {
+            *field = self.field(*start..start.saturating_add(*length))?;
+        }
+        Ok(fields)
+    }
+    /// Compares the content with `other`, ordering runs of digits by their
+    /// value, so `file2` comes before `file10`
+    fn cmp_natural(&self, other: &str) -> Ordering {
+        natural::cmp_natural(self.as_str(), other)
+    }
+    /// Returns a `Cursor` for parsing the content from the start
+    fn cursor(&self) -> Cursor<'_> {
+        Cursor::new(self.as_str())
+    }
+    /// Returns the number of occurrences of `character`
+    fn count_char(&self, character: char) -> usize {
+        search::count_char(self.as_str(), character)
+    }
+    /// Returns the number of lines, separated by `\n` or `\r\n`. A trailing
+    /// line break doesn't start another line.
+    fn count_lines(&self) -> usize {
+        self.as_str().lines().count()
+    }
+    /// Returns the number of words, separated by Unicode whitespace
+    fn count_words(&self) -> usize {
+        self.as_str().split_whitespace().count()
+    }
+    /// Index a byte of the underlying buffer, which may be past the content.
+    /// Every implementor checks `index` against the capacity, not the length.
+    fn get(&self, index: usize) -> Result<&CHARACTER, FixedStringError>;
+    /// Parses the content into another type
+    fn parse<T: FromStr>(&self) -> Result<T, FixedStringError>
+    where
+        Self: Sized,
+    {
+        parse::parse(self.as_str())
+    }
+    /// Parses the content as an integer in the given radix
+    fn parse_radix<T: FromStrRadix>(&self, radix: u32) -> Result<T, FixedStringError>
+    where
+        Self: Sized,
+    {
+        parse::parse_radix(self.as_str(), radix)
+    }
+}
+
+/// Mutable access to a UTF-8 fixed string
+pub trait FixedStringRef: FixedStringView + fmt::Write {
     /// Clear the content of a `FixedString`
     fn clear(&mut self);
+    /// Shortens the content to `length` bytes, which has to be on a character
+    /// boundary. Has no effect if the content is already shorter.
+    fn truncate(&mut self, length: usize) -> Result<(), FixedStringError>;
     /// Assigns a value to the `FixedString`, truncating if necessary.
     fn assign(&mut self, string: &str) -> Result<(), FixedStringError>;
     /// Appends a string slice to the `FixedString`, truncating if necessary.
     fn push(&mut self, s: &str) -> Result<(), FixedStringError>;
     /// Appends a character to the `FixedString`, if it's not full
     fn push_char(&mut self, character: char) -> Result<(), FixedStringError>;
-    /// Concatinates another fixed string with self
-    fn concatinate(&mut self, other: &dyn FixedStringRef) -> Result<(), FixedStringError>;
-    /// Index a character
-    fn get<'a>(&'a self, index: usize) -> Result<&'a CHARACTER, FixedStringError>;
-    /// Index a character
-    fn get_mut<'a>(&'a mut self, index: usize) -> Result<&'a mut CHARACTER, FixedStringError>;
+    /// Appends UTF-8 encoded bytes, if they are valid and fit
+    fn push_bytes(&mut self, bytes: &[CHARACTER]) -> Result<(), FixedStringError> {
+        match str::from_utf8(bytes) {
+            Ok(string) => self.push(string),
+            Err(_) => Err(FixedStringError::InvalidUtf8),
+        }
+    }
+    /// Appends the content of another fixed string
+    fn concatenate(&mut self, other: &dyn FixedStringView) -> Result<(), FixedStringError> {
+        self.push(other.as_str())
+    }
+    /// Appends the content of another fixed string
+    #[deprecated(note = "use `concatenate`, or `concat` for any string-like type")]
+    fn concatinate(&mut self, other: &dyn FixedStringView) -> Result<(), FixedStringError> {
+        self.concatenate(other)
+    }
+    /// Appends anything string-like, e.g. a `&str`, another fixed string or a
+    /// heapless `String`
+    fn concat(&mut self, other: impl AsRef<str>) -> Result<(), FixedStringError>
+    where
+        Self: Sized,
+    {
+        self.push(other.as_ref())
+    }
+    /// Index a byte of the underlying buffer mutably, checked against the
+    /// capacity like `get`
+    fn get_mut(&mut self, index: usize) -> Result<&mut CHARACTER, FixedStringError>;
+}
+
+/// Searching with a `Pattern`, a `char`, `&str` or `Fn(char) -> bool`.
+/// Implemented for every `FixedStringView`, including `dyn FixedStringView`.
+pub trait FixedStringSearch: FixedStringView {
+    /// Returns the byte index of the first occurrence of `pattern`
+    fn find_pattern<P: Pattern>(&self, pattern: P) -> Option<usize> {
+        pattern.find_in(self.as_str()).map(|(start, _)| start)
+    }
+    /// Iterates over the non-overlapping occurrences of `pattern`
+    fn matches<P: Pattern>(&self, pattern: P) -> Matches<'_, P> {
+        Matches::new(self.as_str(), pattern)
+    }
+    /// Iterates over the non-overlapping occurrences of `pattern` together
+    /// with their byte index
+    fn match_indices<P: Pattern>(&self, pattern: P) -> MatchIndices<'_, P> {
+        MatchIndices::new(self.as_str(), pattern)
+    }
+    /// Iterates over the parts separated by `pattern`
+    fn split<P: Pattern>(&self, pattern: P) -> Split<'_, P> {
+        Split::new(self.as_str(), pattern)
+    }
+    /// Returns the content with all matches of `pattern` at both ends removed
+    fn trim_matches<P: Pattern>(&self, pattern: P) -> &str {
+        pattern::trim_end_matches(pattern::trim_start_matches(self.as_str(), pattern), pattern)
+    }
+    /// Returns the content with all matches of `pattern` at its start removed
+    fn trim_start_matches<P: Pattern>(&self, pattern: P) -> &str {
+        pattern::trim_start_matches(self.as_str(), pattern)
+    }
+    /// Returns the content with all matches of `pattern` at its end removed
+    fn trim_end_matches<P: Pattern>(&self, pattern: P) -> &str {
+        pattern::trim_end_matches(self.as_str(), pattern)
+    }
+    /// Returns the content without one match of `pattern` at its start
+    fn strip_prefix<P: Pattern>(&self, pattern: P) -> Option<&str> {
+        pattern.strip_prefix_of(self.as_str())
+    }
+    /// Returns the content without one match of `pattern` at its end
+    fn strip_suffix<P: Pattern>(&self, pattern: P) -> Option<&str> {
+        pattern.strip_suffix_of(self.as_str())
+    }
 }
 
+impl<T: FixedStringView + ?Sized> FixedStringSearch for T {}
+
+// Lets the generic `FixedStringView` methods be called on a `&dyn` or `&mut
+// dyn` trait object
+macro_rules! impl_view_for_reference {
+    ($($reference:ty),*) => {$(
+        impl<T: FixedStringView + ?Sized> FixedStringView for $reference {
+            fn as_str(&self) -> &str {
+                (**self).as_str()
+            }
+
+            fn is_full(&self) -> bool {
+                (**self).is_full()
+            }
+
+            fn length(&self) -> usize {
+                (**self).length()
+            }
+
+            fn capacity(&self) -> usize {
+                (**self).capacity()
+            }
+
+            fn get(&self, index: usize) -> Result<&CHARACTER, FixedStringError> {
+                (**self).get(index)
+            }
+        }
+    )*};
+}
+
+impl_view_for_reference!(&T, &mut T);
+
+#[allow(clippy::upper_case_acronyms)]
 type CHARACTER = u8;
 const CHARACTER_NONE: CHARACTER = 0;
 
-#[derive(Copy, Eq)]
-pub struct FixedString<const N: usize> {
-    buffer: [CHARACTER; N],
-    length: usize,
+/// A string stored in a fixed size buffer of `N` code units, UTF-8 encoded
+/// bytes unless another `Encoding` is given.
+///
+/// The length is stored as a `usize` unless another `LengthType` is given.
+///
+/// The units past the length are set to `E::NONE` on creation. `new` is a
+/// `const fn`, so a string in a `static` is zeroed by the startup code rather
+/// than at runtime.
+///
+/// The layout is `repr(C)`, the buffer followed by the length, so a
+/// `FixedString` can be embedded in structs shared with C code.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct FixedString<const N: usize, E: Encoding = Utf8, L: LengthType = usize> {
+    buffer: [E::Unit; N],
+    length: L,
 }
 
-impl<const N: usize> FixedString<N> {
+/// A string stored as UTF-16 code units in a fixed size buffer of `N` units
+pub type FixedWideString<const N: usize> = FixedString<N, Utf16>;
+
+impl<const N: usize, E: Encoding, L: LengthType> FixedString<N, E, L> {
     /// Creates a new empty `FixedString`.
     pub const fn new() -> Self {
+        const { assert!(N <= L::MAX, "the capacity doesn't fit the length type") };
+
         Self {
-            buffer: [CHARACTER_NONE; N],
-            length: 0,
+            buffer: [E::NONE; N],
+            length: L::ZERO,
+        }
+    }
+
+    /// Clear a `FixedString`. Only the length is reset, the content is left in
+    /// the buffer. Use `secure_erase` to wipe it.
+    pub fn clear(&mut self) {
+        self.length = L::ZERO;
+    }
+
+    /// Clear a `FixedString` and overwrite the whole buffer with `E::NONE`, in
+    /// a way that isn't optimized away
+    pub fn secure_erase(&mut self) {
+        for unit in self.buffer.iter_mut() {
+            unsafe { ptr::write_volatile(unit, E::NONE) };
+        }
+        atomic::compiler_fence(atomic::Ordering::SeqCst);
+        self.length = L::ZERO;
+    }
+
+    /// Returns `true` if the `FixedString` is full.
+    pub fn is_full(&self) -> bool {
+        self.length() == N
+    }
+
+    /// Returns the current length of the `FixedString` in code units
+    pub fn length(&self) -> usize {
+        self.length.to_usize()
+    }
+
+    /// Returns the total capacity of the `FixedString` in code units
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the code units of the content
+    pub fn as_units(&self) -> &[E::Unit] {
+        self.buffer.get(..self.length()).unwrap_or_default()
+    }
+
+    /// Returns the code units of the content mutably
+    pub(crate) fn as_units_mut(&mut self) -> &mut [E::Unit] {
+        let length = self.length();
+        self.buffer.get_mut(..length).unwrap_or_default()
+    }
+
+    /// Retrieve the raw data in the buffer, including whatever is left past
+    /// the content
+    pub fn raw(&self) -> &[E::Unit; N] {
+        &self.buffer
+    }
+
+    /// Returns a pointer to the start of the buffer
+    pub fn as_ptr(&self) -> *const E::Unit {
+        self.buffer.as_ptr()
+    }
+
+    /// Returns a mutable pointer to the start of the buffer
+    pub fn as_mut_ptr(&mut self) -> *mut E::Unit {
+        self.buffer.as_mut_ptr()
+    }
+
+    /// Returns the unused part of the buffer, so it can be filled directly,
+    /// e.g. by a receive routine. Use `set_len` afterwards to include the
+    /// written units in the content.
+    pub fn spare_capacity_mut(&mut self) -> &mut [E::Unit] {
+        let length = self.length();
+        self.buffer.get_mut(length..).unwrap_or_default()
+    }
+
+    /// Sets the length of the content
+    ///
+    /// # Safety
+    ///
+    /// `length` must be at most `N`, and the units up to `length` must hold
+    /// valid content for the encoding.
+    pub unsafe fn set_len(&mut self, length: usize) {
+        self.length = L::from_usize(length);
+    }
+
+    /// Copies `units` into the buffer at `offset`, if they fit. Doesn't update
+    /// the length.
+    pub(crate) fn write_units(
+        &mut self,
+        offset: usize,
+        units: &[E::Unit],
+    ) -> Result<(), FixedStringError> {
+        match offset
+            .checked_add(units.len())
+            .and_then(|end| self.buffer.get_mut(offset..end))
+        {
+            Some(buffer) => {
+                buffer.copy_from_slice(units);
+                Ok(())
+            }
+            None => Err(FixedStringError::Overflow {
+                requested: units.len(),
+                available: N.saturating_sub(offset),
+            }),
+        }
+    }
+
+    /// Returns the length after appending `requested` units, if they fit
+    pub(crate) fn grown_length(&self, requested: usize) -> Result<usize, FixedStringError> {
+        let available = N.saturating_sub(self.length());
+        if requested > available {
+            return Err(FixedStringError::Overflow {
+                requested,
+                available,
+            });
+        }
+
+        Ok(self.length().saturating_add(requested))
+    }
+
+    /// Compares the content with `other`, which may have another capacity or
+    /// length type, in constant time. Every unit of both buffers is read, so
+    /// the time taken depends neither on the lengths nor on where the
+    /// contents differ.
+    pub fn ct_eq<const M: usize, L2: LengthType>(&self, other: &FixedString<M, E, L2>) -> bool {
+        let own_length = self.length();
+        let other_length = other.length();
+        let mut difference = own_length ^ other_length;
+        for i in 0..N.max(M) {
+            let own_mask = ((i < own_length) as u32).wrapping_neg();
+            let other_mask = ((i < other_length) as u32).wrapping_neg();
+            let own: u32 = self.raw().get(i).map_or(0, |unit| (*unit).into());
+            let other: u32 = other.raw().get(i).map_or(0, |unit| (*unit).into());
+            difference |= ((own & own_mask) ^ (other & other_mask)) as usize;
+        }
+        hint::black_box(difference) == 0
+    }
+
+    /// Take the content from a `FixedString`, leaving an empty `FixedString`
+    pub fn take(&mut self) -> Self {
+        let mut res = Self::new();
+        // Fits, as both have the same capacity
+        let _ = res.write_units(0, self.as_units());
+        self.as_units_mut().fill(E::NONE);
+        res.length = self.length;
+        self.length = L::ZERO;
+        res
+    }
+
+    /// Replaces the content of `dest`, which may have another capacity or
+    /// length type, with a copy of the content. `dest` is left unchanged if
+    /// the content doesn't fit.
+    pub fn copy_into<const M: usize, L2: LengthType>(
+        &self,
+        dest: &mut FixedString<M, E, L2>,
+    ) -> Result<(), FixedStringError> {
+        if self.length() > M {
+            return Err(FixedStringError::Overflow {
+                requested: self.length(),
+                available: M,
+            });
+        }
+
+        dest.write_units(0, self.as_units())?;
+        dest.length = L2::from_usize(self.length());
+        Ok(())
+    }
+
+    /// Copies the content to the start of `dest`, e.g. a transmit buffer,
+    /// returning the number of units copied
+    pub fn copy_to_slice(&self, dest: &mut [E::Unit]) -> Result<usize, FixedStringError> {
+        let length = self.length();
+        match dest.get_mut(..length) {
+            Some(dest) => dest.copy_from_slice(self.as_units()),
+            None => {
+                return Err(FixedStringError::Overflow {
+                    requested: length,
+                    available: dest.len(),
+                });
+            }
+        }
+        Ok(length)
+    }
+
+    /// Copies the content followed by a NUL to the start of `dest`, returning
+    /// the number of units copied without the NUL
+    pub fn copy_to_slice_with_nul(&self, dest: &mut [E::Unit]) -> Result<usize, FixedStringError> {
+        let length = self.length();
+        if length >= dest.len() {
+            return Err(FixedStringError::Overflow {
+                requested: length.saturating_add(1),
+                available: dest.len(),
+            });
         }
+
+        self.copy_to_slice(dest)?;
+        if let Some(nul) = dest.get_mut(length) {
+            *nul = E::NONE;
+        }
+        Ok(length)
+    }
+
+    /// Returns the content in an array of `M` units padded with `pad`, e.g.
+    /// for a fixed size name field in a protocol struct
+    pub fn to_padded_array<const M: usize>(
+        &self,
+        pad: E::Unit,
+    ) -> Result<[E::Unit; M], FixedStringError> {
+        let mut array = [pad; M];
+        self.copy_to_slice(&mut array)?;
+        Ok(array)
+    }
+
+    /// Appends code units to the `FixedString`, if they fit
+    fn push_units(&mut self, units: &[E::Unit]) -> Result<(), FixedStringError> {
+        let length = self.grown_length(units.len())?;
+        self.write_units(self.length(), units)?;
+        self.length = L::from_usize(length);
+
+        Ok(())
     }
+}
 
+impl<const N: usize, E: TextEncoding, L: LengthType> FixedString<N, E, L> {
     /// Creates a new empty `FixedString` with an assigned value
     pub fn new_with(string: &str) -> Result<Self, FixedStringError> {
         let mut res = Self::new();
-        match res.assign(string) {
+        match res.push(string) {
             Ok(()) => Ok(res),
             Err(err) => Err(err),
         }
     }
 
-    /// Clear a `FixedString`
-    pub fn clear(&mut self) {
-        self.length = 0;
+    /// Appends a string slice to the `FixedString`, if it fits
+    pub fn push(&mut self, string: &str) -> Result<(), FixedStringError> {
+        let start = self.length();
+        let length = self.grown_length(E::encoded_len(string))?;
+        if let Some(buffer) = self.buffer.get_mut(start..) {
+            E::encode(string, buffer);
+        }
+        self.length = L::from_usize(length);
+
+        Ok(())
     }
 
-    /// Retrieve the raw data in the buffer
-    pub fn raw(&self) -> &[CHARACTER; N] {
-        &self.buffer
+    /// Appends a character to the `FixedString`, if it fits
+    pub fn push_char(&mut self, character: char) -> Result<(), FixedStringError> {
+        let mut encoded = [0; 4];
+        self.push(character.encode_utf8(&mut encoded))
+    }
+
+    /// Appends a string slice without checking the capacity, for hot loops
+    /// where the space has already been checked
+    ///
+    /// # Safety
+    ///
+    /// The encoded `string` must fit in the remaining capacity.
+    pub unsafe fn push_unchecked(&mut self, string: &str) {
+        let length = self.length();
+        let requested = E::encoded_len(string);
+        debug_assert!(length.saturating_add(requested) <= N);
+        E::encode(string, unsafe { self.buffer.get_unchecked_mut(length..) });
+        self.length = L::from_usize(length.wrapping_add(requested));
+    }
+
+    /// Appends a character without checking the capacity
+    ///
+    /// # Safety
+    ///
+    /// The encoded `character` must fit in the remaining capacity.
+    pub unsafe fn push_char_unchecked(&mut self, character: char) {
+        let mut encoded = [0; 4];
+        unsafe { self.push_unchecked(character.encode_utf8(&mut encoded)) }
+    }
+
+    /// Appends anything string-like without checking the capacity
+    ///
+    /// # Safety
+    ///
+    /// The encoded `other` must fit in the remaining capacity.
+    pub unsafe fn concat_unchecked(&mut self, other: impl AsRef<str>) {
+        unsafe { self.push_unchecked(other.as_ref()) }
+    }
+
+    /// Iterate over the characters of the content
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        E::decode(self.as_units())
+    }
+
+    /// Returns the number of characters, unlike `length` which counts code
+    /// units
+    pub fn char_count(&self) -> usize {
+        self.chars().count()
     }
 
     /// Format a `FixedString` with provided arguments
-    pub fn format(args: fmt::Arguments) -> Result<FixedString<N>, FixedStringError> {
-        let mut fixed_string: FixedString<N> = FixedString::new();
+    pub fn format(args: fmt::Arguments) -> Result<Self, FixedStringError> {
+        let mut fixed_string = Self::new();
         match fmt::write(&mut fixed_string, args) {
             Ok(()) => Ok(fixed_string),
             Err(fmt::Error) => Err(FixedStringError::FormatError),
         }
     }
 
-    /// Create a `FixedString` from raw data
-    pub fn from_raw(raw: &[CHARACTER; N]) -> Result<FixedString<N>, FixedStringError> {
+    /// Creates a fixed string from any `Display` value, e.g. an IP address.
+    /// Fails with an `Overflow` if the formatted value doesn't fit.
+    pub fn from_display(value: &impl fmt::Display) -> Result<Self, FixedStringError> {
+        let mut fixed_string = Self::new();
+        fixed_string.push_display(value)?;
+        Ok(fixed_string)
+    }
+
+    /// Convert the content to another encoding and capacity
+    pub fn transcode<const M: usize, F: TextEncoding>(
+        &self,
+    ) -> Result<FixedString<M, F>, FixedStringError> {
+        let mut res = FixedString::<M, F>::new();
+        let mut encoded = [0; 4];
+        for character in self.chars() {
+            res.push(character.encode_utf8(&mut encoded))?;
+        }
+        Ok(res)
+    }
+}
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Returns the byte index of the first occurrence of `pattern`, a `char`,
+    /// `&str` or `Fn(char) -> bool`
+    pub fn find<P: Pattern>(&self, pattern: P) -> Option<usize> {
+        self.find_pattern(pattern)
+    }
+}
+
+impl<const N: usize> FixedString<N> {
+    /// Create a `FixedString` by copying `length` bytes of UTF-8 from `ptr`
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of `length` bytes.
+    pub unsafe fn from_raw_parts(
+        ptr: *const CHARACTER,
+        length: usize,
+    ) -> Result<FixedString<N>, FixedStringError> {
+        if length > N {
+            return Err(FixedStringError::Overflow {
+                requested: length,
+                available: N,
+            });
+        }
+
+        let bytes = unsafe { slice::from_raw_parts(ptr, length) };
+        if str::from_utf8(bytes).is_err() {
+            return Err(FixedStringError::InvalidUtf8);
+        }
+
         let mut fixed_string: FixedString<N> = FixedString::new();
+        fixed_string.write_units(0, bytes)?;
+        fixed_string.length = length;
+
+        Ok(fixed_string)
+    }
+
+    /// Create a `FixedString` from raw data, NUL-terminated unless it fills
+    /// the whole array
+    pub fn from_raw(raw: &[CHARACTER; N]) -> Result<FixedString<N>, FixedStringError> {
+        let length = raw
+            .iter()
+            .position(|character| *character == CHARACTER_NONE)
+            .unwrap_or(N);
+        Self::from_raw_with_len(raw, length)
+    }
 
-        let mut length = N;
-        for i in 0..N {
-            if raw[i] == CHARACTER_NONE {
-                length = i;
-                break;
+    /// Create a `FixedString` from the first `length` bytes of raw data. NULs
+    /// are kept as part of the content.
+    pub fn from_raw_with_len(
+        raw: &[CHARACTER; N],
+        length: usize,
+    ) -> Result<FixedString<N>, FixedStringError> {
+        if length > N {
+            return Err(FixedStringError::Overflow {
+                requested: length,
+                available: N,
+            });
+        }
+
+        let raw = match raw.get(..length) {
+            Some(raw) => raw,
+            None => {
+                return Err(FixedStringError::Overflow {
+                    requested: length,
+                    available: N,
+                });
             }
-            fixed_string.buffer[i] = raw[i];
+        };
+        if str::from_utf8(raw).is_err() {
+            return Err(FixedStringError::InvalidUtf8);
         }
+
+        let mut fixed_string: FixedString<N> = FixedString::new();
+        fixed_string.write_units(0, raw)?;
         fixed_string.length = length;
 
         Ok(fixed_string)
     }
 
+    /// Returns the content as bytes for in-place modification
+    ///
+    /// # Safety
+    ///
+    /// The content has to be valid UTF-8 when the borrow ends
+    pub unsafe fn as_mut_bytes(&mut self) -> &mut [CHARACTER] {
+        self.as_units_mut()
+    }
+
+    /// Inserts `string` at byte `index`, if it fits. `index` has to be on a
+    /// character boundary.
+    pub fn insert_str(&mut self, index: usize, string: &str) -> Result<(), FixedStringError> {
+        if !self.as_str().is_char_boundary(index) {
+            return Err(FixedStringError::InvalidIndex {
+                index,
+                length: self.length,
+            });
+        }
+
+        let length = self.grown_length(string.len())?;
+        copy_within_checked(
+            &mut self.buffer,
+            index..self.length,
+            index.saturating_add(string.len()),
+        );
+        self.write_units(index, string.as_bytes())?;
+        self.length = length;
+
+        Ok(())
+    }
+
+    /// Inserts a character at byte `index`, if it fits. `index` has to be on a
+    /// character boundary.
+    pub fn insert(&mut self, index: usize, character: char) -> Result<(), FixedStringError> {
+        let mut encoded = [0; 4];
+        self.insert_str(index, character.encode_utf8(&mut encoded))
+    }
+
+    /// Removes and returns the character starting at byte `index`
+    pub fn remove(&mut self, index: usize) -> Result<char, FixedStringError> {
+        let character = match self
+            .as_str()
+            .get(index..)
+            .and_then(|tail| tail.chars().next())
+        {
+            Some(character) => character,
+            None => {
+                return Err(FixedStringError::InvalidIndex {
+                    index,
+                    length: self.length,
+                });
+            }
+        };
+
+        let end = index.saturating_add(character.len_utf8());
+        copy_within_checked(&mut self.buffer, end..self.length, index);
+        self.length = self.length.saturating_sub(character.len_utf8());
+
+        Ok(character)
+    }
+
     /// Standard iterator
     pub fn iter(&self) -> FixedStringIterator<'_, N> {
         FixedStringIterator {
@@ -120,61 +1037,131 @@ impl<const N: usize> FixedString<N> {
         }
     }
 
-    /// Take the content from a `FixedString`, leaving an empty `FixedString`
-    pub fn take(&mut self) -> Self {
-        let mut res = Self::new();
-        for i in 0..self.length {
-            res.buffer[i] = self.buffer[i];
-            self.buffer[i] = CHARACTER_NONE;
+    /// Copy bytes that are known to be valid UTF-8 and fit the capacity
+    #[cfg(any(feature = "heapless", feature = "arrayvec"))]
+    fn from_valid_bytes(bytes: &[CHARACTER]) -> Self {
+        let mut fixed_string = Self::new();
+        let _ = fixed_string.write_units(0, bytes);
+        fixed_string.length = bytes.len();
+        fixed_string
+    }
+
+    /// Returns the content as a NUL-terminated `&CStr`. The NUL is written to
+    /// the buffer directly after the content, so there has to be room for it.
+    pub fn as_c_str(&mut self) -> Result<&CStr, FixedStringError> {
+        if self.length == N {
+            return Err(FixedStringError::Overflow {
+                requested: 1,
+                available: 0,
+            });
         }
-        res.length = self.length;
-        self.length = 0;
-        res
+
+        if let Some(index) = self
+            .as_units()
+            .iter()
+            .position(|character| *character == CHARACTER_NONE)
+        {
+            return Err(FixedStringError::InteriorNul { index });
+        }
+
+        self.write_units(self.length, &[CHARACTER_NONE])?;
+        let bytes = self.buffer.get(..=self.length).unwrap_or_default();
+        match CStr::from_bytes_with_nul(bytes) {
+            Ok(c_str) => Ok(c_str),
+            Err(_) => Err(FixedStringError::InteriorNul { index: self.length }),
+        }
+    }
+
+    /// Appends as many whole characters from UTF-8 encoded bytes as fits,
+    /// returning the number of bytes written
+    #[cfg(any(feature = "std", feature = "embedded-io"))]
+    fn write_utf8(&mut self, bytes: &[CHARACTER]) -> Result<usize, FixedStringError> {
+        let string = match str::from_utf8(bytes) {
+            Ok(string) => string,
+            Err(_) => return Err(FixedStringError::InvalidUtf8),
+        };
+
+        let string = string.get(..string.floor_char_boundary(N.saturating_sub(self.length)));
+        let copied = string.unwrap_or_default().as_bytes();
+        self.write_units(self.length, copied)?;
+        self.length = self.length.saturating_add(copied.len());
+
+        Ok(copied.len())
     }
 
-    pub fn get_ref<'a>(&'a self) -> &'a dyn FixedStringRef {
+    pub fn get_ref(&self) -> &dyn FixedStringRef {
         self as &dyn FixedStringRef
     }
 
-    pub fn get_ref_mut<'a>(&'a mut self) -> &'a mut dyn FixedStringRef {
+    pub fn get_ref_mut(&mut self) -> &mut dyn FixedStringRef {
         self as &mut dyn FixedStringRef
     }
 }
 
-impl<const CAPACITY: usize> FixedStringRef for FixedString<CAPACITY> {
-    fn as_str<'a>(&'a self) -> &'a str
+impl<const CAPACITY: usize, L: LengthType> FixedStringView for FixedString<CAPACITY, Utf8, L> {
+    fn as_str(&self) -> &str
     where
         [(); CAPACITY]:,
     {
-        unsafe { str::from_utf8_unchecked(&self.buffer[..self.length]) }
+        unsafe { str::from_utf8_unchecked(self.as_units()) }
     }
 
     /// Returns `true` if the `FixedString` is full.
     fn is_full(&self) -> bool {
-        self.length == CAPACITY
+        FixedString::<CAPACITY, Utf8, L>::is_full(self)
     }
 
     /// Returns the current length of the `FixedString`
     fn length(&self) -> usize {
-        self.length
+        FixedString::<CAPACITY, Utf8, L>::length(self)
     }
 
     /// Returns the total capacity of the `FixedString`
     fn capacity(&self) -> usize {
-        CAPACITY
+        FixedString::<CAPACITY, Utf8, L>::capacity(self)
     }
 
+    /// Index a byte of the buffer, which may be past the content
+    fn get(&self, index: usize) -> Result<&CHARACTER, FixedStringError> {
+        if index >= CAPACITY {
+            return Err(FixedStringError::InvalidIndex {
+                index,
+                length: CAPACITY,
+            });
+        }
+
+        self.raw().get(index).ok_or(FixedStringError::InvalidIndex {
+            index,
+            length: CAPACITY,
+        })
+    }
+}
+
+impl<const CAPACITY: usize, L: LengthType> FixedStringRef for FixedString<CAPACITY, Utf8, L> {
     /// Clear the content of a `FixedString`
     fn clear(&mut self) {
-        for i in 0..CAPACITY {
-            self.buffer[i] = CHARACTER_NONE;
+        FixedString::<CAPACITY, Utf8, L>::clear(self)
+    }
+
+    fn truncate(&mut self, length: usize) -> Result<(), FixedStringError> {
+        if length >= self.length() {
+            return Ok(());
         }
-        self.length = 0;
+
+        if !self.as_str().is_char_boundary(length) {
+            return Err(FixedStringError::InvalidIndex {
+                index: length,
+                length: self.length(),
+            });
+        }
+
+        self.length = L::from_usize(length);
+        Ok(())
     }
 
     /// Assigns a value to the `FixedString`, truncating if necessary.
     fn assign(&mut self, string: &str) -> Result<(), FixedStringError> {
-        if self.length != 0 {
+        if self.length() != 0 {
             return Err(FixedStringError::AlreadyAssigned);
         }
 
@@ -183,105 +1170,150 @@ impl<const CAPACITY: usize> FixedStringRef for FixedString<CAPACITY> {
 
     /// Appends a string slice to the `FixedString`, truncating if necessary.
     fn push(&mut self, string: &str) -> Result<(), FixedStringError> {
-        if self.length + string.len() > CAPACITY {
-            return Err(FixedStringError::Overflow);
-        }
-
-        let bytes_to_copy = string.as_bytes();
-        let copy_len = string.len().min(CAPACITY - self.length);
-        for i in 0..min(CAPACITY - self.length, copy_len) {
-            self.buffer[self.length + i] = bytes_to_copy[i] as CHARACTER;
-        }
-        self.length += copy_len;
-
-        return Ok(());
+        FixedString::<CAPACITY, Utf8, L>::push(self, string)
     }
 
     /// Appends a character to the `FixedString`, if it's not full
     fn push_char(&mut self, character: char) -> Result<(), FixedStringError> {
-        if self.length + 1 > CAPACITY {
-            return Err(FixedStringError::Overflow);
-        }
-        self.buffer[self.length] = character as CHARACTER;
-        self.length += 1;
+        FixedString::<CAPACITY, Utf8, L>::push_char(self, character)
+    }
 
-        Ok(())
+    fn concatenate(&mut self, other: &dyn FixedStringView) -> Result<(), FixedStringError> {
+        self.push_units(other.as_str().as_bytes())
     }
 
-    fn concatinate(&mut self, other: &dyn FixedStringRef) -> Result<(), FixedStringError> {
-        if self.length + other.length() > CAPACITY {
-            return Err(FixedStringError::Overflow);
+    /// Index a byte of the buffer mutably, which may be past the content
+    fn get_mut(&mut self, index: usize) -> Result<&mut CHARACTER, FixedStringError> {
+        if index >= CAPACITY {
+            return Err(FixedStringError::InvalidIndex {
+                index,
+                length: CAPACITY,
+            });
         }
 
-        for index in 0..other.length() {
-            let offset = self.length + index;
-            self.buffer[offset] = *other.get(index)?;
+        match self.buffer.get_mut(index) {
+            Some(unit) => Ok(unit),
+            None => Err(FixedStringError::InvalidIndex {
+                index,
+                length: CAPACITY,
+            }),
         }
-        self.length += other.length();
-
-        Ok(())
     }
+}
 
-    fn get<'a>(&'a self, index: usize) -> Result<&'a CHARACTER, FixedStringError> {
-        if index >= CAPACITY {
-            return Err(FixedStringError::InvalidIndex);
-        }
+impl<const N: usize, L: LengthType> AsRef<str> for FixedString<N, Utf8, L> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
 
-        Ok(&self.buffer[index])
+/// Copies `units[source]` to start at `destination`, like `copy_within`, if
+/// both ranges are inside `units`
+pub(crate) fn copy_within_checked<T: Copy>(
+    units: &mut [T],
+    source: Range<usize>,
+    destination: usize,
+) {
+    let fits = source.start <= source.end
+        && source.end <= units.len()
+        && destination
+            .checked_add(source.len())
+            .is_some_and(|end| end <= units.len());
+    if fits {
+        units.copy_within(source, destination);
     }
+}
 
-    fn get_mut<'a>(&'a mut self, index: usize) -> Result<&'a mut CHARACTER, FixedStringError> {
-        if index >= CAPACITY {
-            return Err(FixedStringError::InvalidIndex);
-        }
+/// Returns the name of `T` without its module path
+fn short_type_name<T>() -> &'static str {
+    let name = any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
 
-        Ok(&mut self.buffer[index])
+/// Writes `characters` quoted, with quotes, control characters and
+/// non-printables escaped like `str`'s `Debug` does
+pub(crate) fn write_escaped(
+    characters: impl Iterator<Item = char>,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    f.write_str("\"")?;
+    for character in characters {
+        write!(f, "{}", character.escape_debug())?;
     }
+    f.write_str("\"")
 }
 
-impl<const N: usize> fmt::Debug for FixedString<N> {
+/// Writes the content like `Display`, or with the alternate flag, `{:#?}`,
+/// together with the type and length and with invisible characters escaped,
+/// e.g. `FixedString<64> { len: 12, "Hello\nWorld" }` or
+/// `FixedString<16, Utf16, u8> { len: 2, "Hi" }`
+impl<const N: usize, E: TextEncoding, L: LengthType> fmt::Debug for FixedString<N, E, L> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.as_str())
+        if !f.alternate() {
+            return E::fmt(self.as_units(), f);
+        }
+
+        // Like the type is written, leaving out parameters that are defaults
+        write!(f, "FixedString<{}", N)?;
+        let (encoding, length_type) = (short_type_name::<E>(), short_type_name::<L>());
+        if length_type != short_type_name::<usize>() {
+            write!(f, ", {}, {}", encoding, length_type)?;
+        } else if encoding != short_type_name::<Utf8>() {
+            write!(f, ", {}", encoding)?;
+        }
+        write!(f, "> {{ len: {}, ", self.length())?;
+        write_escaped(E::decode(self.as_units()), f)?;
+        f.write_str(" }")
     }
 }
 
-impl<const N: usize> fmt::Display for FixedString<N> {
+impl<const N: usize, E: TextEncoding, L: LengthType> fmt::Display for FixedString<N, E, L> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.as_str())
+        E::fmt(self.as_units(), f)
     }
 }
 
-// Implementing `fmt::Write` for `FixedString` to make it a custom writer.
-impl<const N: usize> fmt::Write for FixedString<N> {
-    fn write_str(&mut self, string: &str) -> fmt::Result {
-        // Check if there's enough space in the buffer
-        if self.length + string.len() > N {
-            return Err(fmt::Error); // Buffer overflow
+/// Dumps the code units of the content as hex, e.g. `48656c6c6f` for "Hello"
+impl<const N: usize, E: Encoding, L: LengthType> fmt::LowerHex for FixedString<N, E, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = mem::size_of::<E::Unit>().saturating_mul(2);
+        for unit in self.as_units() {
+            write!(f, "{:0width$x}", unit)?;
         }
+        Ok(())
+    }
+}
 
-        // Copy the string into the buffer
-        let bytes_to_copy = string.as_bytes();
-        let copy_len = string.len().min(N - self.length);
-        for i in 0..min(N - self.length, copy_len) {
-            self.buffer[self.length + i] = bytes_to_copy[i] as CHARACTER;
+/// Dumps the code units of the content as hex, e.g. `48656C6C6F` for "Hello"
+impl<const N: usize, E: Encoding, L: LengthType> fmt::UpperHex for FixedString<N, E, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = mem::size_of::<E::Unit>().saturating_mul(2);
+        for unit in self.as_units() {
+            write!(f, "{:0width$X}", unit)?;
         }
-        self.length += copy_len;
-
         Ok(())
     }
+}
 
-    fn write_char(&mut self, character: char) -> fmt::Result {
-        // Check if there's enough space in the buffer
-        if self.length + 1 > N {
-            return Err(fmt::Error); // Buffer overflow
+/// Dumps the code units of the content as binary
+impl<const N: usize, E: Encoding, L: LengthType> fmt::Binary for FixedString<N, E, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = mem::size_of::<E::Unit>().saturating_mul(8);
+        for unit in self.as_units() {
+            write!(f, "{:0width$b}", unit)?;
         }
+        Ok(())
+    }
+}
 
-        // Copy the character into the buffer
-        let bytes_to_copy = [character as u8];
-        self.buffer[self.length..self.length + 1].copy_from_slice(&bytes_to_copy);
-        self.length += 1;
+// Implementing `fmt::Write` for `FixedString` to make it a custom writer.
+impl<const N: usize, E: TextEncoding, L: LengthType> fmt::Write for FixedString<N, E, L> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push(string).map_err(|_| fmt::Error)
+    }
 
-        Ok(())
+    fn write_char(&mut self, character: char) -> fmt::Result {
+        self.push_char(character).map_err(|_| fmt::Error)
     }
 }
 
@@ -296,74 +1328,77 @@ impl<'a, const N: usize> Iterator for FixedStringIterator<'a, N> {
     type Item = char;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.position >= self.content.length {
-            return None;
-        }
-
-        let raw = self.content.buffer[self.position] as char;
-        self.position += 1;
+        let raw = *self.content.as_units().get(self.position)? as char;
+        self.position = self.position.saturating_add(1);
 
         Some(raw)
     }
 }
 
-/// Clone a fixed string
-impl<const N: usize> Clone for FixedString<N> {
-    fn clone_from(&mut self, source: &Self) {
-        self.clear();
-        for i in 0..source.length {
-            self.buffer[i] = source.buffer[i];
-        }
-        self.length = source.length;
-    }
-
-    fn clone(&self) -> Self {
-        let mut dest = Self::new();
-        dest.clone_from(self);
-        dest
-    }
-}
-
-/// Indexing into a Fixed String
-impl<const CAPACITY: usize> Index<usize> for FixedString<CAPACITY> {
-    type Output = CHARACTER;
+/// Indexing into a Fixed String, panicking if `index` is past the content.
+/// Use `get` where a panic isn't acceptable.
+#[allow(clippy::panic, clippy::indexing_slicing)]
+impl<const CAPACITY: usize, E: Encoding, L: LengthType> Index<usize>
+    for FixedString<CAPACITY, E, L>
+{
+    type Output = E::Unit;
 
     fn index(&self, index: usize) -> &Self::Output {
-        if index >= self.length {
+        if index >= self.length() {
             panic!("Tried to access none-existing index {}", index);
         }
 
-        &self.buffer[index]
+        &self.as_units()[index]
     }
 }
 
-impl<const CAPACITY: usize> IndexMut<usize> for FixedString<CAPACITY> {
+#[allow(clippy::panic, clippy::indexing_slicing)]
+impl<const CAPACITY: usize, E: Encoding, L: LengthType> IndexMut<usize>
+    for FixedString<CAPACITY, E, L>
+{
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        if index >= self.length {
+        if index >= self.length() {
             panic!("Tried to access none-existing index {}", index);
         }
 
-        &mut self.buffer[index]
+        &mut self.as_units_mut()[index]
     }
 }
 
-impl<const CAPACITY: usize> PartialEq for FixedString<CAPACITY> {
-    fn eq(&self, other: &Self) -> bool {
-        if self.length != other.length {
-            return false;
-        }
+/// Compares the content, so strings of different capacities or length types
+/// can be compared directly
+impl<const N: usize, const M: usize, E: Encoding, L: LengthType, L2: LengthType>
+    PartialEq<FixedString<M, E, L2>> for FixedString<N, E, L>
+{
+    fn eq(&self, other: &FixedString<M, E, L2>) -> bool {
+        self.as_units() == other.as_units()
+    }
+}
 
-        for i in 0..self.length {
-            if self.buffer[i] != other.buffer[i] {
-                return false;
-            }
-        }
+impl<const CAPACITY: usize, E: Encoding, L: LengthType> Eq for FixedString<CAPACITY, E, L> {}
+
+impl<const N: usize, const M: usize, E: Encoding, L: LengthType, L2: LengthType>
+    PartialOrd<FixedString<M, E, L2>> for FixedString<N, E, L>
+where
+    E::Unit: Ord,
+{
+    fn partial_cmp(&self, other: &FixedString<M, E, L2>) -> Option<Ordering> {
+        Some(self.as_units().cmp(other.as_units()))
+    }
+}
 
-        return true;
+/// Orders by the code units of the content, which for UTF-8 is the same as
+/// the ordering of `str`
+impl<const CAPACITY: usize, E: Encoding, L: LengthType> Ord for FixedString<CAPACITY, E, L>
+where
+    E::Unit: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_units().cmp(other.as_units())
     }
 }
 
-impl<const CAPACITY: usize> Default for FixedString<CAPACITY> {
+impl<const CAPACITY: usize, E: Encoding, L: LengthType> Default for FixedString<CAPACITY, E, L> {
     fn default() -> Self {
         Self::new()
     }
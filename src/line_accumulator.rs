@@ -0,0 +1,137 @@
+use core::{
+    default::Default,
+    option::Option::{self, None, Some},
+    str,
+};
+
+use crate::{FixedString, FixedStringView};
+
+/// What is done with input that doesn't fit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Truncation {
+    /// Keeps as much of the input as fits
+    Truncate,
+    /// Drops the input
+    Discard,
+}
+
+/// Collects bytes, e.g. from a UART receive interrupt, into lines ended by
+/// `\n` or `\r\n`. Invalid UTF-8 is replaced with `U+FFFD`.
+#[derive(Debug, Clone)]
+pub struct LineAccumulator<const N: usize> {
+    line: FixedString<N>,
+    /// The bytes of a character that isn't complete yet
+    partial: [u8; 4],
+    partial_length: usize,
+    /// Whether a `\r` was received that may be part of a line break
+    carriage_return: bool,
+    truncation: Truncation,
+    truncated: bool,
+    complete: bool,
+}
+
+impl<const N: usize> LineAccumulator<N> {
+    /// Creates a new `LineAccumulator`, handling lines longer than `N` bytes
+    /// as described by `truncation`
+    pub const fn new(truncation: Truncation) -> Self {
+        Self {
+            line: FixedString::new(),
+            partial: [0; 4],
+            partial_length: 0,
+            carriage_return: false,
+            truncation,
+            truncated: false,
+            complete: false,
+        }
+    }
+
+    /// Adds a byte, returning the line without its line break if the byte
+    /// completed one. Lines too long to fit are either truncated or skipped.
+    pub fn push_byte(&mut self, byte: u8) -> Option<&FixedString<N>> {
+        if self.complete {
+            self.clear();
+        }
+
+        if self.partial_length > 0 && !is_continuation(byte) {
+            self.push_char(char::REPLACEMENT_CHARACTER);
+        }
+
+        if byte == b'\n' {
+            self.carriage_return = false;
+            if self.truncated && self.truncation == Truncation::Discard {
+                self.clear();
+                return None;
+            }
+            self.complete = true;
+            return Some(&self.line);
+        }
+
+        if self.carriage_return {
+            self.carriage_return = false;
+            self.push_char('\r');
+        }
+        if byte == b'\r' {
+            self.carriage_return = true;
+            return None;
+        }
+
+        if let Some(partial) = self.partial.get_mut(self.partial_length) {
+            *partial = byte;
+        }
+        self.partial_length = self.partial_length.saturating_add(1);
+        let width = match self.partial[0] {
+            0x00..0x80 => 1,
+            0xc2..0xe0 => 2,
+            0xe0..0xf0 => 3,
+            0xf0..0xf5 => 4,
+            _ => 0,
+        };
+        if self.partial_length >= width {
+            let character =
+                str::from_utf8(self.partial.get(..self.partial_length).unwrap_or_default())
+                    .ok()
+                    .and_then(|character| character.chars().next())
+                    .unwrap_or(char::REPLACEMENT_CHARACTER);
+            self.push_char(character);
+        }
+
+        None
+    }
+
+    /// Returns `true` if the last completed line, or the current one, didn't
+    /// fit and was truncated
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the content of the line received so far
+    pub fn as_str(&self) -> &str {
+        self.line.as_str()
+    }
+
+    /// Drops the line received so far
+    pub fn clear(&mut self) {
+        self.line.clear();
+        self.partial_length = 0;
+        self.carriage_return = false;
+        self.truncated = false;
+        self.complete = false;
+    }
+
+    fn push_char(&mut self, character: char) {
+        self.partial_length = 0;
+        if self.line.push_char(character).is_err() {
+            self.truncated = true;
+        }
+    }
+}
+
+impl<const N: usize> Default for LineAccumulator<N> {
+    fn default() -> Self {
+        Self::new(Truncation::Truncate)
+    }
+}
+
+fn is_continuation(byte: u8) -> bool {
+    byte & 0xc0 == 0x80
+}
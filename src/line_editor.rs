@@ -0,0 +1,202 @@
+use core::{
+    default::Default,
+    fmt,
+    result::Result::{self, Err, Ok},
+    write,
+};
+
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+/// Erases from the terminal cursor to the end of the line
+const ERASE_TO_END: &str = "\x1b[K";
+
+/// Edits a line of input, e.g. from a UART terminal, keeping track of a cursor
+/// within the line. Every edit writes the bytes needed to update the
+/// terminal to an echo sink, using ANSI escape sequences to move the
+/// terminal cursor.
+#[derive(Debug, Clone)]
+pub struct LineEditor<const N: usize> {
+    line: FixedString<N>,
+    cursor: usize,
+    overwrite: bool,
+}
+
+impl<const N: usize> LineEditor<N> {
+    /// Creates a new `LineEditor` with an empty line, in insert mode
+    pub const fn new() -> Self {
+        Self {
+            line: FixedString::new(),
+            cursor: 0,
+            overwrite: false,
+        }
+    }
+
+    /// Returns the current line
+    pub fn as_str(&self) -> &str {
+        self.line.as_str()
+    }
+
+    /// Returns the byte offset of the cursor within the line
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns `true` if typed characters replace the ones under the cursor
+    pub fn is_overwrite(&self) -> bool {
+        self.overwrite
+    }
+
+    /// Switches between insert and overwrite mode
+    pub fn set_overwrite(&mut self, overwrite: bool) {
+        self.overwrite = overwrite;
+    }
+
+    /// Returns the finished line, leaving the editor empty
+    pub fn take_line(&mut self) -> FixedString<N> {
+        self.cursor = 0;
+        self.line.take()
+    }
+
+    /// Replaces the whole line, e.g. with an entry from a `History`, and
+    /// moves the cursor to the end
+    pub fn set_line(
+        &mut self,
+        line: &str,
+        echo: &mut impl fmt::Write,
+    ) -> Result<(), FixedStringError> {
+        let replacement = FixedString::<N>::new_with(line)?;
+        self.home(echo)?;
+        self.line = replacement;
+        self.cursor = self.line.length();
+
+        write!(echo, "{}{}", line, ERASE_TO_END).map_err(|_| FixedStringError::FormatError)
+    }
+
+    /// Types a character at the cursor, inserting or overwriting depending on
+    /// the mode
+    pub fn insert(
+        &mut self,
+        character: char,
+        echo: &mut impl fmt::Write,
+    ) -> Result<(), FixedStringError> {
+        let replaced = match self.overwrite {
+            true => self.after_cursor().chars().next(),
+            false => None,
+        };
+        let replaced_len = replaced.map_or(0, char::len_utf8);
+
+        let available = N
+            .saturating_sub(self.line.length())
+            .saturating_add(replaced_len);
+        if character.len_utf8() > available {
+            return Err(FixedStringError::Overflow {
+                requested: character.len_utf8(),
+                available,
+            });
+        }
+
+        if replaced.is_some() {
+            self.line.remove(self.cursor)?;
+        }
+        self.line.insert(self.cursor, character)?;
+        self.cursor = self.cursor.saturating_add(character.len_utf8());
+
+        write!(echo, "{}", character).map_err(|_| FixedStringError::FormatError)?;
+        self.redraw_tail(echo)
+    }
+
+    /// Removes the character before the cursor
+    pub fn backspace(&mut self, echo: &mut impl fmt::Write) -> Result<(), FixedStringError> {
+        let Some(character) = self.before_cursor().chars().next_back() else {
+            return Ok(());
+        };
+
+        self.cursor = self.cursor.saturating_sub(character.len_utf8());
+        self.line.remove(self.cursor)?;
+
+        write!(echo, "\x08").map_err(|_| FixedStringError::FormatError)?;
+        self.redraw_tail(echo)
+    }
+
+    /// Removes the character under the cursor
+    pub fn delete(&mut self, echo: &mut impl fmt::Write) -> Result<(), FixedStringError> {
+        if self.cursor == self.line.length() {
+            return Ok(());
+        }
+
+        self.line.remove(self.cursor)?;
+        self.redraw_tail(echo)
+    }
+
+    /// Moves the cursor one character to the left
+    pub fn left(&mut self, echo: &mut impl fmt::Write) -> Result<(), FixedStringError> {
+        let Some(character) = self.before_cursor().chars().next_back() else {
+            return Ok(());
+        };
+
+        self.cursor = self.cursor.saturating_sub(character.len_utf8());
+        move_cursor(echo, 1, 'D')
+    }
+
+    /// Moves the cursor one character to the right
+    pub fn right(&mut self, echo: &mut impl fmt::Write) -> Result<(), FixedStringError> {
+        let Some(character) = self.after_cursor().chars().next() else {
+            return Ok(());
+        };
+
+        self.cursor = self.cursor.saturating_add(character.len_utf8());
+        move_cursor(echo, 1, 'C')
+    }
+
+    /// Moves the cursor to the start of the line
+    pub fn home(&mut self, echo: &mut impl fmt::Write) -> Result<(), FixedStringError> {
+        let count = self.before_cursor().chars().count();
+        self.cursor = 0;
+        move_cursor(echo, count, 'D')
+    }
+
+    /// Moves the cursor to the end of the line
+    pub fn end(&mut self, echo: &mut impl fmt::Write) -> Result<(), FixedStringError> {
+        let count = self.after_cursor().chars().count();
+        self.cursor = self.line.length();
+        move_cursor(echo, count, 'C')
+    }
+
+    /// Returns the part of the line before the cursor
+    fn before_cursor(&self) -> &str {
+        self.line.as_str().get(..self.cursor).unwrap_or_default()
+    }
+
+    /// Returns the part of the line from the cursor to the end
+    fn after_cursor(&self) -> &str {
+        self.line.as_str().get(self.cursor..).unwrap_or_default()
+    }
+
+    /// Rewrites the line from the cursor to the end, clearing what was left
+    /// on the terminal, and moves the terminal cursor back
+    fn redraw_tail(&self, echo: &mut impl fmt::Write) -> Result<(), FixedStringError> {
+        let tail = self.after_cursor();
+        write!(echo, "{}{}", tail, ERASE_TO_END).map_err(|_| FixedStringError::FormatError)?;
+        move_cursor(echo, tail.chars().count(), 'D')
+    }
+}
+
+/// Moves the terminal cursor `count` steps in `direction`, `C` for right and
+/// `D` for left
+fn move_cursor(
+    echo: &mut impl fmt::Write,
+    count: usize,
+    direction: char,
+) -> Result<(), FixedStringError> {
+    if count == 0 {
+        return Ok(());
+    }
+
+    write!(echo, "\x1b[{}{}", count, direction).map_err(|_| FixedStringError::FormatError)
+}
+
+impl<const N: usize> Default for LineEditor<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,99 @@
+use core::{
+    cell::RefCell,
+    fmt::{self, Write},
+    ops::FnOnce,
+    result::Result::{self, Err, Ok},
+    write,
+};
+
+use critical_section::Mutex;
+use log::{LevelFilter, Log, Metadata, Record};
+
+use crate::{FixedString, FixedStringError, FixedStringRingBuffer, FixedStringView, Truncation};
+
+/// A `log` backend keeping the last `K` records, each formatted into a
+/// `FixedString<N>` as `LEVEL message`. Place it in a `static` and register it
+/// with `log::set_logger`.
+pub struct FixedStringLogger<const N: usize, const K: usize> {
+    entries: Mutex<RefCell<FixedStringRingBuffer<N, K>>>,
+    level: LevelFilter,
+    truncation: Truncation,
+}
+
+impl<const N: usize, const K: usize> FixedStringLogger<N, K> {
+    /// Creates a new `FixedStringLogger` storing records up to `level`
+    pub const fn new(level: LevelFilter, truncation: Truncation) -> Self {
+        Self {
+            entries: Mutex::new(RefCell::new(FixedStringRingBuffer::new())),
+            level,
+            truncation,
+        }
+    }
+
+    /// Returns the most verbose level that is stored
+    pub fn level(&self) -> LevelFilter {
+        self.level
+    }
+
+    /// Runs `f` with the stored records, e.g. to dump them after a fault.
+    /// `f` must not log through this logger, records logged from inside it are
+    /// dropped. Returns `Busy` if called again from within `f`.
+    pub fn with<R>(
+        &self,
+        f: impl FnOnce(&mut FixedStringRingBuffer<N, K>) -> R,
+    ) -> Result<R, FixedStringError> {
+        critical_section::with(|cs| match self.entries.borrow(cs).try_borrow_mut() {
+            Ok(mut entries) => Ok(f(&mut entries)),
+            Err(_) => Err(FixedStringError::Busy),
+        })
+    }
+}
+
+impl<const N: usize, const K: usize> Log for FixedStringLogger<N, K> {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let mut writer = TruncatingWriter::<N> {
+            entry: FixedString::new(),
+            truncated: false,
+        };
+        let _ = write!(writer, "{} {}", record.level(), record.args());
+        if writer.truncated && self.truncation == Truncation::Discard {
+            return;
+        }
+
+        // Drop the record if it is logged from inside `with`, which holds the borrow
+        critical_section::with(|cs| {
+            if let Ok(mut entries) = self.entries.borrow(cs).try_borrow_mut() {
+                // Fits, as the entry has the capacity of the ring buffer entries
+                let _ = entries.push_overwrite(writer.entry.as_str());
+            }
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+/// Writes as much as fits into `entry` and drops the rest
+struct TruncatingWriter<const N: usize> {
+    entry: FixedString<N>,
+    truncated: bool,
+}
+
+impl<const N: usize> fmt::Write for TruncatingWriter<N> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        let split = string.floor_char_boundary(N.saturating_sub(self.entry.length()));
+
+        self.truncated |= split < string.len();
+        self.entry
+            .push(string.get(..split).unwrap_or_default())
+            .map_err(|_| fmt::Error)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use core::{iter::Iterator, option::Option};
+
+use memchr::{memchr, memchr_iter, memmem};
+
+/// Returns the byte index of the first occurrence of `needle` in `haystack`
+pub(crate) fn find(haystack: &str, needle: &str) -> Option<usize> {
+    memmem::find(haystack.as_bytes(), needle.as_bytes())
+}
+
+/// Returns the byte index of the first occurrence of `character` in
+/// `haystack`
+pub(crate) fn find_char(haystack: &str, character: char) -> Option<usize> {
+    if character.is_ascii() {
+        return memchr(character as u8, haystack.as_bytes());
+    }
+
+    let mut encoded = [0; 4];
+    find(haystack, character.encode_utf8(&mut encoded))
+}
+
+/// Returns the number of occurrences of `character` in `haystack`
+pub(crate) fn count_char(haystack: &str, character: char) -> usize {
+    if character.is_ascii() {
+        return memchr_iter(character as u8, haystack.as_bytes()).count();
+    }
+
+    let mut encoded = [0; 4];
+    let needle = character.encode_utf8(&mut encoded).as_bytes();
+    memmem::find_iter(haystack.as_bytes(), needle).count()
+}
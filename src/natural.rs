@@ -0,0 +1,57 @@
+use core::{
+    cmp::{Ord, Ordering},
+    iter::Iterator,
+    option::Option::{None, Some},
+};
+
+/// Compares `a` and `b` with runs of ASCII digits ordered by their value, so
+/// `file2` comes before `file10`. Runs of equal value, like `07` and `7`, are
+/// ordered by their length if the rest is equal.
+pub(crate) fn cmp_natural(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    // Decides between equal strings with differently padded numbers
+    let mut padding = Ordering::Equal;
+
+    loop {
+        let (Some(a_char), Some(b_char)) = (a.chars().next(), b.chars().next()) else {
+            return a.len().cmp(&b.len()).then(padding);
+        };
+
+        if a_char.is_ascii_digit() && b_char.is_ascii_digit() {
+            let (a_digits, a_rest) = split_digits(a);
+            let (b_digits, b_rest) = split_digits(b);
+            let (a_value, b_value) = (
+                a_digits.trim_start_matches('0'),
+                b_digits.trim_start_matches('0'),
+            );
+            let ordering = a_value
+                .len()
+                .cmp(&b_value.len())
+                .then_with(|| a_value.cmp(b_value));
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+
+            padding = padding.then(a_digits.len().cmp(&b_digits.len()));
+            (a, b) = (a_rest, b_rest);
+            continue;
+        }
+
+        match a_char.cmp(&b_char) {
+            Ordering::Equal => {
+                a = a.get(a_char.len_utf8()..).unwrap_or_default();
+                b = b.get(b_char.len_utf8()..).unwrap_or_default();
+            }
+            ordering => return ordering,
+        }
+    }
+}
+
+/// Splits `text` after its leading run of ASCII digits
+fn split_digits(text: &str) -> (&str, &str) {
+    let length = match text.find(|character: char| !character.is_ascii_digit()) {
+        Some(length) => length,
+        None => text.len(),
+    };
+    text.split_at(length)
+}
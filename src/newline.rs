@@ -0,0 +1,72 @@
+use core::{
+    iter,
+    option::Option::Some,
+    result::Result::{self, Ok},
+};
+
+use crate::{FixedString, FixedStringError, LengthType, Utf8};
+
+/// Line break written by `normalize_newlines`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\n`, e.g. for log storage
+    Lf,
+    /// `\r\n`, e.g. for a modem link
+    CrLf,
+}
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Converts every `\n` and `\r\n` line break to `ending` in place. A lone
+    /// `\r` is left as is. Nothing is changed if the content grows beyond the
+    /// capacity.
+    pub fn normalize_newlines(&mut self, ending: LineEnding) -> Result<(), FixedStringError> {
+        match ending {
+            LineEnding::Lf => {
+                let units = self.as_units_mut();
+                let mut length = 0;
+                for read in 0..units.len() {
+                    let unit = units.get(read).copied().unwrap_or_default();
+                    if unit == b'\r' && units.get(read.saturating_add(1)) == Some(&b'\n') {
+                        continue;
+                    }
+                    if let Some(dest) = units.get_mut(length) {
+                        *dest = unit;
+                    }
+                    length = length.saturating_add(1);
+                }
+                self.length = L::from_usize(length);
+            }
+            LineEnding::CrLf => {
+                let units = self.as_units();
+                let requested = units
+                    .iter()
+                    .zip(iter::once(&0).chain(units))
+                    .filter(|(unit, previous)| **unit == b'\n' && **previous != b'\r')
+                    .count();
+                let length = self.length();
+                let new_length = self.grown_length(requested)?;
+
+                // Move the content back to front, so nothing is overwritten
+                // before it is read
+                let mut write = new_length;
+                for read in (0..length).rev() {
+                    let unit = self.raw().get(read).copied().unwrap_or_default();
+                    let previous = read.checked_sub(1).and_then(|i| self.raw().get(i).copied());
+                    write = write.saturating_sub(1);
+                    if let Some(dest) = self.buffer.get_mut(write) {
+                        *dest = unit;
+                    }
+                    if unit == b'\n' && previous != Some(b'\r') {
+                        write = write.saturating_sub(1);
+                        if let Some(dest) = self.buffer.get_mut(write) {
+                            *dest = b'\r';
+                        }
+                    }
+                }
+                self.length = L::from_usize(new_length);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use core::{
+    iter::Iterator,
+    option::Option::{None, Some},
+    result::Result::{self, Err, Ok},
+};
+
+use crate::{
+    FixedString, FixedStringError, FixedStringRef, FixedStringView, HexCase, LengthType, Utf8,
+    hex::hex_value,
+};
+
+/// XORs the bytes of a sentence between the leading `$` or `!` and the `*`
+fn checksum(body: &str) -> u8 {
+    body.bytes().fold(0, |checksum, byte| checksum ^ byte)
+}
+
+/// Returns the body of `sentence` between the leading `$` or `!` and the end
+fn body(sentence: &str) -> Result<&str, FixedStringError> {
+    sentence
+        .strip_prefix(['$', '!'])
+        .ok_or(FixedStringError::ParseError)
+}
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Creates an NMEA sentence from `fields` separated by commas, like
+    /// `$GPGLL,4916.45,N*2D`, where the first field is the talker and type
+    pub fn from_nmea_fields(fields: &[&str]) -> Result<Self, FixedStringError> {
+        let mut res = Self::new_with("$")?;
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                res.push(",")?;
+            }
+            res.push(field)?;
+        }
+        res.push_nmea_checksum()?;
+        Ok(res)
+    }
+
+    /// Appends the `*hh` checksum of the sentence, which must start with `$`
+    /// or `!`
+    pub fn push_nmea_checksum(&mut self) -> Result<(), FixedStringError> {
+        let checksum = checksum(body(self.as_str())?);
+        if self.remaining_capacity() < 3 {
+            return Err(FixedStringError::Overflow {
+                requested: 3,
+                available: self.remaining_capacity(),
+            });
+        }
+
+        self.push("*")?;
+        self.push_hex(&[checksum], HexCase::Upper)
+    }
+
+    /// Returns the fields of the sentence between the `$` or `!` and the
+    /// `*hh` checksum, ignoring a trailing line break. A missing or wrong
+    /// checksum is rejected with a `ParseError`.
+    pub fn verify_nmea_checksum(&self) -> Result<&str, FixedStringError> {
+        let sentence = self.as_str().trim_end_matches(['\r', '\n']);
+        let (fields, digits) = body(sentence)?
+            .rsplit_once('*')
+            .ok_or(FixedStringError::ParseError)?;
+
+        let expected = match digits.as_bytes() {
+            [high, low] => hex_value(*high)
+                .zip(hex_value(*low))
+                .map(|(high, low)| (high << 4) | low),
+            _ => None,
+        };
+        if expected != Some(checksum(fields)) {
+            return Err(FixedStringError::ParseError);
+        }
+
+        Ok(fields)
+    }
+
+    /// Verifies the checksum and removes it, together with any trailing
+    /// line break
+    pub fn strip_nmea_checksum(&mut self) -> Result<(), FixedStringError> {
+        let length = self.verify_nmea_checksum()?.len().saturating_add(1);
+        self.truncate(length)
+    }
+}
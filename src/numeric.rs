@@ -0,0 +1,220 @@
+use core::{
+    convert::Into,
+    result::Result::{self, Err, Ok},
+    str,
+};
+
+use crate::{FixedString, FixedStringError, LengthType, TextEncoding};
+
+/// The most decimals `push_f32_fixed` can write
+const MAX_DECIMALS: u8 = 9;
+
+/// Room for a sign, the 39 digits of `u128::MAX`, a point and `MAX_DECIMALS`
+const FLOAT_LENGTH: usize = 1 + 39 + 1 + MAX_DECIMALS as usize;
+
+/// How digits past the requested number of decimals are removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round to the nearest value, with halfway cases away from zero
+    Nearest,
+    /// Drop the remaining digits
+    Truncate,
+}
+
+/// Describes how `push_f32_formatted` writes a float
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FloatFormat {
+    /// Number of digits after the point, at most 9
+    pub decimals: u8,
+    pub rounding: Rounding,
+    /// Written for NaN
+    pub nan: &'static str,
+    /// Written for infinity, after a `-` if negative
+    pub infinity: &'static str,
+}
+
+impl FloatFormat {
+    /// Creates a format with `decimals` digits after the point, rounding to
+    /// the nearest value and writing `NaN` and `inf`
+    pub const fn new(decimals: u8) -> Self {
+        Self {
+            decimals,
+            rounding: Rounding::Nearest,
+            nan: "NaN",
+            infinity: "inf",
+        }
+    }
+}
+
+/// Digits for every radix up to 36
+const DIGITS: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Room for the 128 binary digits of `u128::MAX`
+const UINT_LENGTH: usize = 128;
+
+/// Writes the digits of `value` to the end of `out`, returning where they start
+fn write_digits(value: u128, out: &mut [u8]) -> usize {
+    write_digits_radix(value, 10, out)
+}
+
+/// Writes the digits of `value` in `radix` to the end of `out`, returning where
+/// they start
+pub(crate) fn write_digits_radix(mut value: u128, radix: u32, out: &mut [u8]) -> usize {
+    let radix = u128::from(radix);
+    let mut start = out.len();
+    while let Some(index) = start.checked_sub(1) {
+        let digit = DIGITS.get(value.checked_rem(radix).unwrap_or_default() as usize);
+        if let (Some(out), Some(digit)) = (out.get_mut(index), digit) {
+            *out = *digit;
+        }
+        start = index;
+        value = value.checked_div(radix).unwrap_or_default();
+        if value == 0 {
+            break;
+        }
+    }
+    start
+}
+
+impl<const N: usize, E: TextEncoding, L: LengthType> FixedString<N, E, L> {
+    /// Appends an unsigned integer in `radix`, padded with leading zeros to at
+    /// least `width` digits, e.g. `0007` or `00FF`. Digits above 9 are
+    /// uppercase. A radix outside `2..=36` is rejected with a `FormatError`.
+    pub fn push_uint_padded(
+        &mut self,
+        value: impl Into<u128>,
+        width: usize,
+        radix: u32,
+    ) -> Result<(), FixedStringError> {
+        if !(2..=36).contains(&radix) {
+            return Err(FixedStringError::FormatError);
+        }
+
+        let mut output = [0; UINT_LENGTH];
+        let start = write_digits_radix(value.into(), radix, &mut output);
+        let digits = unsafe { str::from_utf8_unchecked(output.get(start..).unwrap_or_default()) };
+
+        let padding = width.saturating_sub(digits.len());
+        self.grown_length(padding.saturating_add(digits.len()))?;
+
+        for _ in 0..padding {
+            self.push("0")?;
+        }
+        self.push(digits)
+    }
+
+    /// Appends a float with a fixed number of decimals, without going through
+    /// `core::fmt`. See `push_f32_formatted`.
+    pub fn push_f32_fixed(&mut self, value: f32, decimals: u8) -> Result<(), FixedStringError> {
+        self.push_f32_formatted(value, &FloatFormat::new(decimals))
+    }
+
+    /// Appends a float as described by `format`, if it fits. More than 9
+    /// decimals are rejected with a `FormatError`.
+    pub fn push_f32_formatted(
+        &mut self,
+        value: f32,
+        format: &FloatFormat,
+    ) -> Result<(), FixedStringError> {
+        if format.decimals > MAX_DECIMALS {
+            return Err(FixedStringError::FormatError);
+        }
+
+        if value.is_nan() {
+            return self.push(format.nan);
+        }
+
+        if value.is_infinite() {
+            if value.is_sign_negative() {
+                let length = self.length;
+                self.push("-")?;
+                if let Err(err) = self.push(format.infinity) {
+                    self.length = length;
+                    return Err(err);
+                }
+                return Ok(());
+            }
+            return self.push(format.infinity);
+        }
+
+        let magnitude = value.abs();
+        let scale = 10u32.pow(format.decimals as u32);
+        let mut integer = magnitude as u128;
+        let fraction = (magnitude - integer as f32) * scale as f32;
+        let mut fraction = match format.rounding {
+            Rounding::Nearest => (fraction + 0.5) as u32,
+            Rounding::Truncate => fraction as u32,
+        };
+        if fraction >= scale {
+            integer = integer.saturating_add(1);
+            fraction = fraction.saturating_sub(scale);
+        }
+
+        self.push_decimal(value.is_sign_negative(), integer, fraction, format.decimals)
+    }
+
+    /// Appends a signed fixed-point number with `frac_bits` fractional bits,
+    /// e.g. 16 for Q16.16, with `decimals` digits after the point, rounded to
+    /// the nearest value. No floats are involved. More than 9 decimals or 32
+    /// fractional bits are rejected with a `FormatError`.
+    pub fn push_fixed_point(
+        &mut self,
+        raw: i32,
+        frac_bits: u8,
+        decimals: u8,
+    ) -> Result<(), FixedStringError> {
+        if decimals > MAX_DECIMALS || frac_bits > 32 {
+            return Err(FixedStringError::FormatError);
+        }
+
+        let magnitude = raw.unsigned_abs() as u128;
+        let mut integer = magnitude >> frac_bits;
+        let scale = 10u128.pow(decimals as u32);
+        let mask = (1u128 << frac_bits).wrapping_sub(1);
+        let mut fraction = (magnitude & mask).saturating_mul(scale);
+        if frac_bits > 0 {
+            fraction = fraction.saturating_add(1 << frac_bits.saturating_sub(1)) >> frac_bits;
+        }
+        if fraction >= scale {
+            integer = integer.saturating_add(1);
+            fraction = fraction.saturating_sub(scale);
+        }
+
+        self.push_decimal(raw < 0, integer, fraction as u32, decimals)
+    }
+
+    /// Appends `integer` and `fraction`, padded to `decimals` digits, as a
+    /// decimal number
+    fn push_decimal(
+        &mut self,
+        negative: bool,
+        integer: u128,
+        fraction: u32,
+        decimals: u8,
+    ) -> Result<(), FixedStringError> {
+        let mut output = [0; FLOAT_LENGTH];
+        let mut start = FLOAT_LENGTH;
+
+        if decimals > 0 {
+            let digits_start = write_digits(fraction as u128, &mut output);
+            let decimals_start = start.saturating_sub(decimals as usize);
+            if let Some(zeros) = output.get_mut(decimals_start..digits_start) {
+                zeros.fill(b'0');
+            }
+            start = decimals_start.saturating_sub(1);
+            if let Some(point) = output.get_mut(start) {
+                *point = b'.';
+            }
+        }
+
+        start = write_digits(integer, output.get_mut(..start).unwrap_or_default());
+        if negative {
+            start = start.saturating_sub(1);
+            if let Some(sign) = output.get_mut(start) {
+                *sign = b'-';
+            }
+        }
+
+        self.push(unsafe { str::from_utf8_unchecked(output.get(start..).unwrap_or_default()) })
+    }
+}
@@ -0,0 +1,86 @@
+use core::{
+    fmt,
+    result::Result::{self, Err, Ok},
+};
+
+use crate::{FixedStringError, FixedStringRef};
+
+/// Wraps a fixed string and keeps appending as much as fits, counting the
+/// bytes that had to be dropped and the writes that didn't fit, e.g. to
+/// detect in the field that a log buffer is too small
+#[derive(Debug, Clone, Default)]
+pub struct OverflowStatsWriter<W: FixedStringRef> {
+    inner: W,
+    dropped_bytes: usize,
+    failed_writes: usize,
+}
+
+impl<W: FixedStringRef> OverflowStatsWriter<W> {
+    /// Creates a new `OverflowStatsWriter` appending to `inner`
+    pub const fn new(inner: W) -> Self {
+        Self {
+            inner,
+            dropped_bytes: 0,
+            failed_writes: 0,
+        }
+    }
+
+    /// Appends as much of a string slice as fits, without splitting a
+    /// character, returning an `Overflow` if not all of it did
+    pub fn push(&mut self, string: &str) -> Result<(), FixedStringError> {
+        let available = self.inner.remaining_capacity();
+        let fitting = string.floor_char_boundary(available);
+        let pushed = self.inner.push(string.get(..fitting).unwrap_or_default());
+        if pushed.is_err() || fitting == string.len() {
+            return pushed;
+        }
+
+        self.dropped_bytes = self
+            .dropped_bytes
+            .saturating_add(string.len().saturating_sub(fitting));
+        self.failed_writes = self.failed_writes.saturating_add(1);
+        Err(FixedStringError::Overflow {
+            requested: string.len(),
+            available,
+        })
+    }
+
+    /// Returns the number of bytes dropped because they didn't fit
+    pub fn dropped_bytes(&self) -> usize {
+        self.dropped_bytes
+    }
+
+    /// Returns the number of writes that didn't fit completely
+    pub fn failed_writes(&self) -> usize {
+        self.failed_writes
+    }
+
+    /// Resets the statistics, leaving the content as is
+    pub fn reset_stats(&mut self) {
+        self.dropped_bytes = 0;
+        self.failed_writes = 0;
+    }
+
+    /// Returns a reference to the wrapped string
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped string
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the wrapped string
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: FixedStringRef> fmt::Write for OverflowStatsWriter<W> {
+    /// Never fails, so that the rest of the output is counted as well
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let _ = self.push(s);
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use core::{
+    num::ParseIntError,
+    result::Result::{self, Err, Ok},
+    str::FromStr,
+};
+
+use crate::FixedStringError;
+
+/// Integers that can be parsed from a string in a given radix
+pub trait FromStrRadix: Sized {
+    /// Parses an integer from `string` in `radix`, as `from_str_radix` on
+    /// the integer types
+    fn from_str_radix(string: &str, radix: u32) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! impl_from_str_radix {
+    ($($integer:ty),*) => {
+        $(
+            impl FromStrRadix for $integer {
+                fn from_str_radix(string: &str, radix: u32) -> Result<Self, ParseIntError> {
+                    <$integer>::from_str_radix(string, radix)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_str_radix!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+pub(crate) fn parse<T: FromStr>(string: &str) -> Result<T, FixedStringError> {
+    match string.parse() {
+        Ok(value) => Ok(value),
+        Err(_) => Err(FixedStringError::ParseError),
+    }
+}
+
+pub(crate) fn parse_radix<T: FromStrRadix>(
+    string: &str,
+    radix: u32,
+) -> Result<T, FixedStringError> {
+    if !(2..=36).contains(&radix) {
+        return Err(FixedStringError::ParseError);
+    }
+
+    match T::from_str_radix(string, radix) {
+        Ok(value) => Ok(value),
+        Err(_) => Err(FixedStringError::ParseError),
+    }
+}
@@ -0,0 +1,251 @@
+use core::{
+    iter::Iterator,
+    marker::Copy,
+    option::Option::{self, None, Some},
+};
+
+use crate::search;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Something to search for in a fixed string, a `char`, a `&str` or a
+/// `Fn(char) -> bool` predicate
+pub trait Pattern: sealed::Sealed + Copy {
+    /// Returns the start and end byte index of the first match in `haystack`
+    fn find_in(self, haystack: &str) -> Option<(usize, usize)>;
+    /// Returns `haystack` without a match at its start
+    fn strip_prefix_of(self, haystack: &str) -> Option<&str>;
+    /// Returns `haystack` without a match at its end
+    fn strip_suffix_of(self, haystack: &str) -> Option<&str>;
+}
+
+impl sealed::Sealed for char {}
+impl sealed::Sealed for &str {}
+impl<F: Fn(char) -> bool + Copy> sealed::Sealed for F {}
+
+impl Pattern for char {
+    fn find_in(self, haystack: &str) -> Option<(usize, usize)> {
+        search::find_char(haystack, self)
+            .map(|start| (start, start.saturating_add(self.len_utf8())))
+    }
+
+    fn strip_prefix_of(self, haystack: &str) -> Option<&str> {
+        haystack.strip_prefix(self)
+    }
+
+    fn strip_suffix_of(self, haystack: &str) -> Option<&str> {
+        haystack.strip_suffix(self)
+    }
+}
+
+impl Pattern for &str {
+    fn find_in(self, haystack: &str) -> Option<(usize, usize)> {
+        search::find(haystack, self).map(|start| (start, start.saturating_add(self.len())))
+    }
+
+    fn strip_prefix_of(self, haystack: &str) -> Option<&str> {
+        haystack.strip_prefix(self)
+    }
+
+    fn strip_suffix_of(self, haystack: &str) -> Option<&str> {
+        haystack.strip_suffix(self)
+    }
+}
+
+impl<F: Fn(char) -> bool + Copy> Pattern for F {
+    fn find_in(self, haystack: &str) -> Option<(usize, usize)> {
+        haystack
+            .char_indices()
+            .find(|&(_, character)| self(character))
+            .map(|(start, character)| (start, start.saturating_add(character.len_utf8())))
+    }
+
+    fn strip_prefix_of(self, haystack: &str) -> Option<&str> {
+        let character = haystack
+            .chars()
+            .next()
+            .filter(|&character| self(character))?;
+        haystack.get(character.len_utf8()..)
+    }
+
+    fn strip_suffix_of(self, haystack: &str) -> Option<&str> {
+        let character = haystack
+            .chars()
+            .next_back()
+            .filter(|&character| self(character))?;
+        haystack.get(..haystack.len().saturating_sub(character.len_utf8()))
+    }
+}
+
+/// Returns `haystack` with all matches of `pattern` at its start removed
+pub(crate) fn trim_start_matches<P: Pattern>(mut haystack: &str, pattern: P) -> &str {
+    while let Some(rest) = pattern.strip_prefix_of(haystack) {
+        // An empty pattern matches forever
+        if rest.len() == haystack.len() {
+            break;
+        }
+        haystack = rest;
+    }
+    haystack
+}
+
+/// Returns `haystack` with all matches of `pattern` at its end removed
+pub(crate) fn trim_end_matches<P: Pattern>(mut haystack: &str, pattern: P) -> &str {
+    while let Some(rest) = pattern.strip_suffix_of(haystack) {
+        if rest.len() == haystack.len() {
+            break;
+        }
+        haystack = rest;
+    }
+    haystack
+}
+
+/// Iterator over the matches of a `Pattern` and their byte index, created by
+/// `match_indices`
+#[derive(Debug, Clone)]
+pub struct MatchIndices<'a, P: Pattern> {
+    haystack: &'a str,
+    pattern: P,
+    /// Byte index the next search starts at, past the end when done
+    position: usize,
+}
+
+impl<'a, P: Pattern> MatchIndices<'a, P> {
+    pub(crate) fn new(haystack: &'a str, pattern: P) -> Self {
+        Self {
+            haystack,
+            pattern,
+            position: 0,
+        }
+    }
+}
+
+impl<'a, P: Pattern> Iterator for MatchIndices<'a, P> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.haystack.get(self.position..)?;
+        let (start, end) = match self.pattern.find_in(rest) {
+            Some((start, end)) => (
+                self.position.saturating_add(start),
+                self.position.saturating_add(end),
+            ),
+            None => {
+                self.position = self.haystack.len().saturating_add(1);
+                return None;
+            }
+        };
+
+        // Step past the following character after an empty match, so the
+        // search makes progress
+        self.position = if start == end {
+            end.saturating_add(char_len_at(self.haystack, end))
+        } else {
+            end
+        };
+
+        Some((start, self.haystack.get(start..end).unwrap_or_default()))
+    }
+}
+
+/// Iterator over the matches of a `Pattern`, created by `matches`
+#[derive(Debug, Clone)]
+pub struct Matches<'a, P: Pattern> {
+    inner: MatchIndices<'a, P>,
+}
+
+impl<'a, P: Pattern> Matches<'a, P> {
+    pub(crate) fn new(haystack: &'a str, pattern: P) -> Self {
+        Self {
+            inner: MatchIndices::new(haystack, pattern),
+        }
+    }
+}
+
+impl<'a, P: Pattern> Iterator for Matches<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, matched)| matched)
+    }
+}
+
+/// Iterator over the parts of a fixed string separated by a `Pattern`,
+/// created by `split`
+#[derive(Debug, Clone)]
+pub struct Split<'a, P: Pattern> {
+    haystack: &'a str,
+    pattern: P,
+    /// Byte index the next part starts at
+    start: usize,
+    /// Byte index the next search starts at, past the end when done
+    position: usize,
+    finished: bool,
+}
+
+impl<'a, P: Pattern> Split<'a, P> {
+    pub(crate) fn new(haystack: &'a str, pattern: P) -> Self {
+        Self {
+            haystack,
+            pattern,
+            start: 0,
+            position: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<'a, P: Pattern> Iterator for Split<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        loop {
+            // An empty match at the end splits nothing
+            let found = self
+                .haystack
+                .get(self.position..)
+                .and_then(|rest| self.pattern.find_in(rest))
+                .map(|(start, end)| {
+                    (
+                        self.position.saturating_add(start),
+                        self.position.saturating_add(end),
+                    )
+                })
+                .filter(|&(start, end)| start != end || end != self.haystack.len());
+            let Some((start, end)) = found else {
+                self.finished = true;
+                return self.haystack.get(self.start..);
+            };
+
+            // Neither does an empty match at the start of a part, step past
+            // the following character instead
+            if start == end {
+                self.position = end.saturating_add(char_len_at(self.haystack, end));
+                if start == self.start {
+                    continue;
+                }
+            } else {
+                self.position = end;
+            }
+
+            let part = self.haystack.get(self.start..start).unwrap_or_default();
+            self.start = end;
+            return Some(part);
+        }
+    }
+}
+
+/// Returns the length of the character at byte `index` of `haystack`, or 1
+/// past the end
+fn char_len_at(haystack: &str, index: usize) -> usize {
+    haystack
+        .get(index..)
+        .and_then(|rest| rest.chars().next())
+        .map_or(1, char::len_utf8)
+}
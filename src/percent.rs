@@ -0,0 +1,146 @@
+use core::{
+    result::Result::{self, Err, Ok},
+    str,
+};
+
+use crate::{
+    FixedString, FixedStringError, LengthType, TextEncoding, Utf8, copy_within_checked,
+    hex::{UPPER_DIGITS, hex_pair, hex_value},
+};
+
+/// The set of ASCII characters `push_percent_encoded` leaves unescaped. All
+/// other bytes are written as `%XX`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PercentCharset {
+    /// Only the unreserved characters of RFC 3986, for query parameters
+    Unreserved,
+    /// The unreserved characters plus sub-delimiters, `:` and `@`, for path
+    /// segments
+    PathSegment,
+}
+
+impl PercentCharset {
+    /// Returns `true` if `byte` is written as is
+    fn contains(self, byte: u8) -> bool {
+        let unreserved = byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~');
+        match self {
+            PercentCharset::Unreserved => unreserved,
+            PercentCharset::PathSegment => {
+                unreserved
+                    || matches!(
+                        byte,
+                        b'!' | b'$'
+                            | b'&'
+                            | b'\''
+                            | b'('
+                            | b')'
+                            | b'*'
+                            | b'+'
+                            | b','
+                            | b';'
+                            | b'='
+                            | b':'
+                            | b'@'
+                    )
+            }
+        }
+    }
+}
+
+impl<const N: usize, E: TextEncoding, L: LengthType> FixedString<N, E, L> {
+    /// Appends `string` with all bytes outside of `charset` percent-encoded,
+    /// if it fits
+    pub fn push_percent_encoded(
+        &mut self,
+        string: &str,
+        charset: PercentCharset,
+    ) -> Result<(), FixedStringError> {
+        let requested = string
+            .bytes()
+            .map(|byte| if charset.contains(byte) { 1 } else { 3 })
+            .sum();
+        self.grown_length(requested)?;
+
+        for byte in string.bytes() {
+            let [high, low] = hex_pair(byte, UPPER_DIGITS);
+            let encoded = [b'%', high, low];
+            let encoded = if charset.contains(byte) {
+                &[byte][..]
+            } else {
+                &encoded[..]
+            };
+            self.push(unsafe { str::from_utf8_unchecked(encoded) })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Decodes the byte starting at `index`, returning it and the number of units
+/// it was encoded in
+fn decode_at(units: &[u8], index: usize) -> Result<(u8, usize), FixedStringError> {
+    match units.get(index) {
+        Some(b'%') => {}
+        Some(unit) => return Ok((*unit, 1)),
+        None => return Err(FixedStringError::InvalidHex { index }),
+    }
+
+    let high = units
+        .get(index.saturating_add(1))
+        .copied()
+        .and_then(hex_value);
+    let low = units
+        .get(index.saturating_add(2))
+        .copied()
+        .and_then(hex_value);
+    match (high, low) {
+        (Some(high), Some(low)) => Ok(((high << 4) | low, 3)),
+        _ => Err(FixedStringError::InvalidHex { index }),
+    }
+}
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Decodes `%XX` escapes in place. The content is left unchanged if an
+    /// escape is malformed or the decoded content isn't valid UTF-8.
+    pub fn percent_decode_in_place(&mut self) -> Result<(), FixedStringError> {
+        // Validate the decoded content in chunks first, carrying characters
+        // split between chunks over to the next one
+        let units = self.as_units_mut();
+        let mut chunk = [0; 64];
+        let mut filled = 0;
+        let mut read = 0;
+        while read < units.len() {
+            let (byte, consumed) = decode_at(units, read)?;
+            if let Some(unit) = chunk.get_mut(filled) {
+                *unit = byte;
+            }
+            filled = filled.saturating_add(1);
+            read = read.saturating_add(consumed);
+
+            if filled == chunk.len() || read == units.len() {
+                filled = match str::from_utf8(chunk.get(..filled).unwrap_or_default()) {
+                    Ok(_) => 0,
+                    Err(err) if err.error_len().is_none() && read != units.len() => {
+                        copy_within_checked(&mut chunk, err.valid_up_to()..filled, 0);
+                        filled.saturating_sub(err.valid_up_to())
+                    }
+                    Err(_) => return Err(FixedStringError::InvalidUtf8),
+                };
+            }
+        }
+
+        let mut read = 0;
+        let mut write = 0;
+        while read < units.len() {
+            let (byte, consumed) = decode_at(units, read)?;
+            if let Some(unit) = units.get_mut(write) {
+                *unit = byte;
+            }
+            write = write.saturating_add(1);
+            read = read.saturating_add(consumed);
+        }
+
+        self.length = L::from_usize(write);
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use core::{
+    cell::UnsafeCell,
+    default::Default,
+    fmt,
+    iter::Iterator,
+    marker::Sync,
+    ops::{Deref, DerefMut, Drop},
+    option::Option::{self, None, Some},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::FixedString;
+
+/// A fixed number of `FixedString`s that can be handed out from a `static`,
+/// e.g. to pass messages from an interrupt handler to a task. Each string is
+/// borrowed through a `PooledFixedString` and returned to the pool when the
+/// guard is dropped.
+pub struct FixedStringPool<const N: usize, const COUNT: usize> {
+    slots: [UnsafeCell<FixedString<N>>; COUNT],
+    taken: [AtomicBool; COUNT],
+}
+
+// A slot is only accessed through the single guard that took it
+unsafe impl<const N: usize, const COUNT: usize> Sync for FixedStringPool<N, COUNT> {}
+
+impl<const N: usize, const COUNT: usize> FixedStringPool<N, COUNT> {
+    /// Creates a new pool where all strings are available
+    pub const fn new() -> Self {
+        Self {
+            slots: [const { UnsafeCell::new(FixedString::new()) }; COUNT],
+            taken: [const { AtomicBool::new(false) }; COUNT],
+        }
+    }
+
+    /// Takes an empty string from the pool, or returns `None` if all strings
+    /// are in use
+    pub fn acquire(&self) -> Option<PooledFixedString<'_, N>> {
+        for (slot, taken) in self.slots.iter().zip(self.taken.iter()) {
+            if taken
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                return Some(PooledFixedString {
+                    string: unsafe { &mut *slot.get() },
+                    taken,
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Returns the number of strings that aren't in use
+    pub fn available(&self) -> usize {
+        self.taken
+            .iter()
+            .filter(|taken| !taken.load(Ordering::Relaxed))
+            .count()
+    }
+}
+
+impl<const N: usize, const COUNT: usize> Default for FixedStringPool<N, COUNT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A `FixedString` borrowed from a `FixedStringPool`. The string is cleared and
+/// returned to the pool on drop.
+pub struct PooledFixedString<'a, const N: usize> {
+    string: &'a mut FixedString<N>,
+    taken: &'a AtomicBool,
+}
+
+impl<const N: usize> Deref for PooledFixedString<'_, N> {
+    type Target = FixedString<N>;
+
+    fn deref(&self) -> &Self::Target {
+        self.string
+    }
+}
+
+impl<const N: usize> DerefMut for PooledFixedString<'_, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.string
+    }
+}
+
+impl<const N: usize> Drop for PooledFixedString<'_, N> {
+    fn drop(&mut self) {
+        self.string.clear();
+        self.taken.store(false, Ordering::Release);
+    }
+}
+
+impl<const N: usize> fmt::Debug for PooledFixedString<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<const N: usize> fmt::Display for PooledFixedString<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
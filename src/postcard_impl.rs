@@ -0,0 +1,18 @@
+use postcard::experimental::max_size::MaxSize;
+
+use crate::FixedString;
+
+/// Number of bytes needed to encode `value` as a postcard varint
+const fn varint_size(mut value: usize) -> usize {
+    let mut size = 1usize;
+    while value >= 0x80 {
+        value >>= 7;
+        size = size.saturating_add(1);
+    }
+    size
+}
+
+/// A `FixedString` is encoded as a varint length prefix followed by at most `N` bytes
+impl<const N: usize> MaxSize for FixedString<N> {
+    const POSTCARD_MAX_SIZE: usize = varint_size(N) + N;
+}
@@ -0,0 +1,198 @@
+use core::{
+    convert::From,
+    iter::Iterator,
+    option::Option::{None, Some},
+    result::Result::{self, Err, Ok},
+    str,
+};
+
+use crate::{FixedString, FixedStringError, LengthType, TextEncoding, numeric::write_digits_radix};
+
+/// An argument for `push_printf`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrintfArg<'a> {
+    Int(i64),
+    Uint(u64),
+    Str(&'a str),
+    Char(char),
+}
+
+macro_rules! impl_from_for_printf_arg {
+    ($variant:ident: $($ty:ty),*) => {
+        $(
+            impl From<$ty> for PrintfArg<'_> {
+                fn from(value: $ty) -> Self {
+                    PrintfArg::$variant(value as _)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_printf_arg!(Int: i8, i16, i32, i64, isize);
+impl_from_for_printf_arg!(Uint: u8, u16, u32, u64, usize);
+
+impl<'a> From<&'a str> for PrintfArg<'a> {
+    fn from(value: &'a str) -> Self {
+        PrintfArg::Str(value)
+    }
+}
+
+impl From<char> for PrintfArg<'_> {
+    fn from(value: char) -> Self {
+        PrintfArg::Char(value)
+    }
+}
+
+/// A parsed conversion like `%-8s` or `%02x`
+struct Spec {
+    left_align: bool,
+    zero_pad: bool,
+    width: usize,
+    conversion: char,
+}
+
+impl<const N: usize, E: TextEncoding, L: LengthType> FixedString<N, E, L> {
+    /// Appends a format string known only at runtime, with each conversion
+    /// replaced by the next of `args`. Supports `%d`, `%i`, `%u`, `%x`, `%X`,
+    /// `%s`, `%c` and `%%`, with the `-` and `0` flags and a width, e.g.
+    /// `%-8s` or `%02x`. An unknown conversion, an argument of the wrong type
+    /// or a wrong number of arguments is rejected with a `FormatError`.
+    /// Nothing is appended if any part fails.
+    pub fn push_printf(
+        &mut self,
+        format: &str,
+        args: &[PrintfArg],
+    ) -> Result<(), FixedStringError> {
+        let length = self.length;
+        let pushed = self.push_printf_parts(format, args);
+        if pushed.is_err() {
+            self.length = length;
+        }
+        pushed
+    }
+
+    fn push_printf_parts(
+        &mut self,
+        mut format: &str,
+        args: &[PrintfArg],
+    ) -> Result<(), FixedStringError> {
+        let mut args = args.iter();
+        while let Some(percent) = format.find('%') {
+            self.push(format.get(..percent).unwrap_or_default())?;
+            let (spec, rest) =
+                parse_spec(format.get(percent.saturating_add(1)..).unwrap_or_default())?;
+            format = rest;
+
+            if spec.conversion == '%' {
+                self.push("%")?;
+                continue;
+            }
+            let arg = args.next().ok_or(FixedStringError::FormatError)?;
+            self.push_printf_arg(&spec, arg)?;
+        }
+
+        if args.next().is_some() {
+            return Err(FixedStringError::FormatError);
+        }
+        self.push(format)
+    }
+
+    fn push_printf_arg(&mut self, spec: &Spec, arg: &PrintfArg) -> Result<(), FixedStringError> {
+        // Room for the 20 digits of `u64::MAX`
+        let mut output = [0; 20];
+        let mut encoded = [0; 4];
+        let number = match (spec.conversion, *arg) {
+            ('d' | 'i', PrintfArg::Int(value)) => Some((value < 0, value.unsigned_abs(), 10)),
+            ('d' | 'i' | 'u', PrintfArg::Uint(value)) => Some((false, value, 10)),
+            ('u', PrintfArg::Int(value)) if value >= 0 => Some((false, value as u64, 10)),
+            ('x' | 'X', PrintfArg::Int(value)) if value >= 0 => Some((false, value as u64, 16)),
+            ('x' | 'X', PrintfArg::Uint(value)) => Some((false, value, 16)),
+            _ => None,
+        };
+        let (sign, text) = match (number, *arg) {
+            (Some((negative, magnitude, radix)), _) => {
+                let start = write_digits_radix(magnitude as u128, radix, &mut output);
+                (
+                    if negative { "-" } else { "" },
+                    output.get(start..).unwrap_or_default(),
+                )
+            }
+            (None, PrintfArg::Str(value)) if spec.conversion == 's' => ("", value.as_bytes()),
+            (None, PrintfArg::Char(value)) if spec.conversion == 'c' => {
+                ("", value.encode_utf8(&mut encoded).as_bytes())
+            }
+            _ => return Err(FixedStringError::FormatError),
+        };
+        let text = unsafe { str::from_utf8_unchecked(text) };
+
+        let padding = spec
+            .width
+            .saturating_sub(sign.len().saturating_add(text.chars().count()));
+        let zero_fill = spec.zero_pad && number.is_some();
+        if !spec.left_align && !zero_fill {
+            self.push_repeated(" ", padding)?;
+        }
+        self.push(sign)?;
+        if !spec.left_align && zero_fill {
+            self.push_repeated("0", padding)?;
+        }
+
+        if spec.conversion == 'x' {
+            for digit in text.bytes() {
+                let digit = [digit.to_ascii_lowercase()];
+                self.push(unsafe { str::from_utf8_unchecked(&digit) })?;
+            }
+        } else {
+            self.push(text)?;
+        }
+
+        if spec.left_align {
+            self.push_repeated(" ", padding)?;
+        }
+        Ok(())
+    }
+
+    fn push_repeated(&mut self, text: &str, count: usize) -> Result<(), FixedStringError> {
+        for _ in 0..count {
+            self.push(text)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the conversion after a `%`, returning it and the rest of the format
+fn parse_spec(format: &str) -> Result<(Spec, &str), FixedStringError> {
+    let mut spec = Spec {
+        left_align: false,
+        zero_pad: false,
+        width: 0,
+        conversion: '%',
+    };
+
+    let mut characters = format.char_indices();
+    for (index, character) in characters.by_ref() {
+        match character {
+            '-' if spec.width == 0 => spec.left_align = true,
+            '0' if spec.width == 0 => spec.zero_pad = true,
+            '0'..='9' => {
+                spec.width = spec
+                    .width
+                    .saturating_mul(10)
+                    .saturating_add(character.to_digit(10).unwrap_or_default() as usize);
+                if spec.width > u16::MAX as usize {
+                    return Err(FixedStringError::FormatError);
+                }
+            }
+            'd' | 'i' | 'u' | 'x' | 'X' | 's' | 'c' => {
+                spec.conversion = character;
+                let rest = format.get(index.saturating_add(1)..).unwrap_or_default();
+                return Ok((spec, rest));
+            }
+            '%' if index == 0 => return Ok((spec, format.get(1..).unwrap_or_default())),
+            _ => return Err(FixedStringError::FormatError),
+        }
+    }
+
+    Err(FixedStringError::FormatError)
+}
@@ -0,0 +1,56 @@
+use core::{
+    iter::Iterator,
+    option::Option::{self, Some},
+    result::Result::{self, Ok},
+    str::Split,
+};
+
+use crate::{FixedString, FixedStringError, FixedStringView, LengthType, Utf8};
+
+/// Iterator over the `key=value` pairs of a query string or form-encoded
+/// body, e.g. `a=1&b=two`. Empty pairs are skipped and a key without `=` has
+/// an empty value. Keys and values are returned still encoded, see
+/// `FixedString::from_form_encoded`.
+#[derive(Debug, Clone)]
+pub struct QueryPairs<'a> {
+    pairs: Split<'a, char>,
+}
+
+impl<'a> QueryPairs<'a> {
+    /// Creates an iterator over the pairs of `query`, without a leading `?`
+    pub fn new(query: &'a str) -> Self {
+        Self {
+            pairs: query.split('&'),
+        }
+    }
+}
+
+impl<'a> Iterator for QueryPairs<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pair = self.pairs.find(|pair| !pair.is_empty())?;
+        Some(pair.split_once('=').unwrap_or((pair, "")))
+    }
+}
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Iterates over the `key=value` pairs of the content
+    pub fn query_pairs(&self) -> QueryPairs<'_> {
+        QueryPairs::new(self.as_str())
+    }
+
+    /// Creates a new `FixedString` from a form-encoded key or value, decoding
+    /// `+` to a space and `%XX` escapes
+    pub fn from_form_encoded(component: &str) -> Result<Self, FixedStringError> {
+        let mut res = Self::new_with(component)?;
+        for unit in res.as_units_mut() {
+            if *unit == b'+' {
+                *unit = b' ';
+            }
+        }
+        res.percent_decode_in_place()?;
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,275 @@
+use core::{
+    clone::Clone,
+    iter::Iterator,
+    marker::Copy,
+    option::Option::{None, Some},
+    result::Result::{self, Err, Ok},
+};
+
+use crate::{FixedString, FixedStringError, FixedStringView, LengthType, Utf8};
+
+/// The most atoms a pattern can have, counting `x+` as two
+const MAX_ATOMS: usize = 64;
+
+/// What a single atom of a pattern matches
+#[derive(Debug, Clone, Copy)]
+enum Matcher {
+    Any,
+    Char(char),
+    Digit,
+    Word,
+    Space,
+    /// A bracket expression, with the byte range of its items in the pattern
+    Class {
+        start: usize,
+        end: usize,
+        negated: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Repeat {
+    One,
+    Optional,
+    Star,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Atom {
+    matcher: Matcher,
+    repeat: Repeat,
+}
+
+const EMPTY_ATOM: Atom = Atom {
+    matcher: Matcher::Any,
+    repeat: Repeat::One,
+};
+
+/// A small regular expression, matched without backtracking and with a fixed
+/// amount of memory. Supports literal characters, `.`, `\d`, `\w`, `\s`,
+/// bracket expressions like `[a-f0-9_]` and `[^,]`, the quantifiers `*`, `+`
+/// and `?` on single atoms, and the anchors `^` and `$`. Groups and
+/// alternation aren't supported.
+#[derive(Debug, Clone)]
+pub struct Regex<'a> {
+    pattern: &'a str,
+    atoms: [Atom; MAX_ATOMS],
+    count: usize,
+    anchored_start: bool,
+    anchored_end: bool,
+}
+
+impl<'a> Regex<'a> {
+    /// Compiles `pattern`, returning a `ParseError` if it's malformed or has
+    /// too many atoms
+    pub fn new(pattern: &'a str) -> Result<Self, FixedStringError> {
+        let mut regex = Self {
+            pattern,
+            atoms: [EMPTY_ATOM; MAX_ATOMS],
+            count: 0,
+            anchored_start: pattern.starts_with('^'),
+            anchored_end: false,
+        };
+
+        let mut characters = pattern.char_indices().skip(regex.anchored_start as usize);
+        // Whether the last atom can take a quantifier
+        let mut quantifiable = false;
+        while let Some((index, character)) = characters.next() {
+            let matcher = match character {
+                '$' if index.saturating_add(1) == pattern.len() => {
+                    regex.anchored_end = true;
+                    break;
+                }
+                '*' | '?' | '+' if quantifiable => {
+                    let last = regex.count.checked_sub(1);
+                    let Some(last) = last.and_then(|last| regex.atoms.get_mut(last)) else {
+                        return Err(FixedStringError::ParseError);
+                    };
+                    match character {
+                        '*' => last.repeat = Repeat::Star,
+                        '?' => last.repeat = Repeat::Optional,
+                        _ => {
+                            let matcher = last.matcher;
+                            regex.add(Atom {
+                                matcher,
+                                repeat: Repeat::Star,
+                            })?
+                        }
+                    }
+                    quantifiable = false;
+                    continue;
+                }
+                '*' | '?' | '+' => return Err(FixedStringError::ParseError),
+                '.' => Matcher::Any,
+                '\\' => match characters.next() {
+                    Some((_, 'd')) => Matcher::Digit,
+                    Some((_, 'w')) => Matcher::Word,
+                    Some((_, 's')) => Matcher::Space,
+                    Some((_, escaped)) => Matcher::Char(escaped),
+                    None => return Err(FixedStringError::ParseError),
+                },
+                '[' => {
+                    let start = index.saturating_add(1);
+                    let negated = pattern
+                        .get(start..)
+                        .is_some_and(|class| class.starts_with('^'));
+                    let start = start.saturating_add(negated as usize);
+                    let end = class_end(pattern, start)?;
+                    // Continue after the closing bracket
+                    while characters.next().is_some_and(|(next, _)| next < end) {}
+                    Matcher::Class {
+                        start,
+                        end,
+                        negated,
+                    }
+                }
+                _ => Matcher::Char(character),
+            };
+
+            regex.add(Atom {
+                matcher,
+                repeat: Repeat::One,
+            })?;
+            quantifiable = true;
+        }
+
+        Ok(regex)
+    }
+
+    /// Returns `true` if `text` contains a match, or is one if the pattern is
+    /// anchored at both ends
+    pub fn is_match(&self, text: &str) -> bool {
+        let accept = 1u128 << self.count;
+        let start = self.closure(1);
+        let mut states = start;
+
+        for character in text.chars() {
+            if states & accept != 0 && !self.anchored_end {
+                return true;
+            }
+
+            let mut next = 0;
+            for (i, atom) in self.atoms.iter().take(self.count).enumerate() {
+                if states & (1 << i) != 0 && self.matches(atom.matcher, character) {
+                    next |= match atom.repeat {
+                        Repeat::Star => 1 << i,
+                        _ => 1 << i.saturating_add(1),
+                    };
+                }
+            }
+
+            states = self.closure(next);
+            if !self.anchored_start {
+                states |= start;
+            }
+            if states == 0 {
+                return false;
+            }
+        }
+
+        states & accept != 0
+    }
+
+    fn add(&mut self, atom: Atom) -> Result<(), FixedStringError> {
+        let slot = self.atoms.get_mut(self.count);
+        *slot.ok_or(FixedStringError::ParseError)? = atom;
+        self.count = self.count.saturating_add(1);
+        Ok(())
+    }
+
+    /// Adds the states reachable by skipping optional atoms
+    fn closure(&self, mut states: u128) -> u128 {
+        for (i, atom) in self.atoms.iter().take(self.count).enumerate() {
+            if states & (1 << i) != 0 && atom.repeat != Repeat::One {
+                states |= 1 << i.saturating_add(1);
+            }
+        }
+        states
+    }
+
+    fn matches(&self, matcher: Matcher, character: char) -> bool {
+        match matcher {
+            Matcher::Any => true,
+            Matcher::Char(expected) => character == expected,
+            Matcher::Digit | Matcher::Word | Matcher::Space => {
+                matches_shorthand(matcher, character)
+            }
+            Matcher::Class {
+                start,
+                end,
+                negated,
+            } => {
+                let class = self.pattern.get(start..end).unwrap_or_default();
+                class_contains(class, character) != negated
+            }
+        }
+    }
+}
+
+fn matches_shorthand(matcher: Matcher, character: char) -> bool {
+    match matcher {
+        Matcher::Digit => character.is_ascii_digit(),
+        Matcher::Word => character.is_alphanumeric() || character == '_',
+        Matcher::Space => character.is_whitespace(),
+        _ => false,
+    }
+}
+
+/// Returns the byte index of the `]` closing the bracket expression whose
+/// items start at `start`
+fn class_end(pattern: &str, start: usize) -> Result<usize, FixedStringError> {
+    let mut characters = pattern.get(start..).unwrap_or_default().char_indices();
+    while let Some((index, character)) = characters.next() {
+        match character {
+            ']' if index == 0 => return Err(FixedStringError::ParseError),
+            ']' => return Ok(start.saturating_add(index)),
+            '\\' => {
+                characters.next();
+            }
+            _ => {}
+        }
+    }
+
+    Err(FixedStringError::ParseError)
+}
+
+/// Returns `true` if `character` matches one of the items of a bracket
+/// expression, like `a-z`, `_` or `\d`
+fn class_contains(items: &str, character: char) -> bool {
+    let mut characters = items.chars();
+    while let Some(first) = characters.next() {
+        let first = match first {
+            '\\' => match characters.next() {
+                Some('d') if matches_shorthand(Matcher::Digit, character) => return true,
+                Some('w') if matches_shorthand(Matcher::Word, character) => return true,
+                Some('s') if matches_shorthand(Matcher::Space, character) => return true,
+                Some('d' | 'w' | 's') | None => continue,
+                Some(escaped) => escaped,
+            },
+            first => first,
+        };
+
+        let mut range = characters.clone();
+        if let (Some('-'), Some(last)) = (range.next(), range.next()) {
+            let last = match last {
+                '\\' => range.next().unwrap_or(last),
+                last => last,
+            };
+            characters = range;
+            if (first..=last).contains(&character) {
+                return true;
+            }
+        } else if first == character {
+            return true;
+        }
+    }
+
+    false
+}
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Returns `true` if the content contains a match of `regex`
+    pub fn matches_regex(&self, regex: &Regex) -> bool {
+        regex.is_match(self.as_str())
+    }
+}
@@ -0,0 +1,56 @@
+use core::result::Result::{self, Err, Ok};
+
+use crate::{FixedString, FixedStringError, FixedStringRef, LengthType, Utf8};
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Changes the length to `new_len` bytes, truncating or padding with
+    /// `fill_char`, e.g. to pad a field to a fixed record width. Truncating
+    /// within a character, or padding by a number of bytes that isn't a
+    /// multiple of the encoded `fill_char`, is rejected with an
+    /// `InvalidIndex`.
+    pub fn resize(&mut self, new_len: usize, fill_char: char) -> Result<(), FixedStringError> {
+        let length = self.length();
+        if new_len > N {
+            return Err(FixedStringError::Overflow {
+                requested: new_len.saturating_sub(length),
+                available: N.saturating_sub(length),
+            });
+        }
+        if new_len <= length {
+            return self.truncate(new_len);
+        }
+
+        let mut encoded = [0; 4];
+        let encoded = fill_char.encode_utf8(&mut encoded).as_bytes();
+        if !new_len.saturating_sub(length).is_multiple_of(encoded.len()) {
+            return Err(FixedStringError::InvalidIndex {
+                index: new_len,
+                length,
+            });
+        }
+
+        self.fill_from(length, new_len, encoded);
+        Ok(())
+    }
+
+    /// Replaces the content with as many copies of `character` as fit in the
+    /// capacity, e.g. to prefill a display line with spaces
+    pub fn fill(&mut self, character: char) {
+        let mut encoded = [0; 4];
+        let encoded = character.encode_utf8(&mut encoded).as_bytes();
+        self.fill_from(
+            0,
+            N.saturating_sub(N.checked_rem(encoded.len()).unwrap_or_default()),
+            encoded,
+        );
+    }
+
+    /// Writes copies of `encoded` from `start` up to `end` and makes `end` the
+    /// length
+    fn fill_from(&mut self, start: usize, end: usize, encoded: &[u8]) {
+        for offset in (start..end).step_by(encoded.len()) {
+            let _ = self.write_units(offset, encoded);
+        }
+        self.length = L::from_usize(end);
+    }
+}
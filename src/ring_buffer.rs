@@ -0,0 +1,121 @@
+use core::{
+    default::Default,
+    fmt,
+    iter::{DoubleEndedIterator, ExactSizeIterator, Iterator},
+    option::Option::{self, None, Some},
+    result::Result::{self, Ok},
+};
+
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+/// Keeps the last `K` strings pushed, e.g. log lines that should survive
+/// until they can be dumped after a fault. When full, pushing overwrites the
+/// oldest entry.
+#[derive(Debug, Clone)]
+pub struct FixedStringRingBuffer<const N: usize, const K: usize> {
+    entries: [FixedString<N>; K],
+    /// Index the next entry is written to
+    head: usize,
+    count: usize,
+}
+
+impl<const N: usize, const K: usize> FixedStringRingBuffer<N, K> {
+    /// Creates a new empty `FixedStringRingBuffer`
+    pub const fn new() -> Self {
+        Self {
+            entries: [FixedString::new(); K],
+            head: 0,
+            count: 0,
+        }
+    }
+
+    /// Returns the number of stored entries
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no entries are stored
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns `true` if the next push overwrites the oldest entry
+    pub fn is_full(&self) -> bool {
+        self.count == K
+    }
+
+    /// Removes all entries
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.count = 0;
+    }
+
+    /// Stores `string` as the newest entry, overwriting the oldest one if
+    /// full. Nothing is stored if `string` doesn't fit in an entry.
+    pub fn push_overwrite(&mut self, string: &str) -> Result<(), FixedStringError> {
+        self.push_overwrite_with(|entry| entry.push(string))
+    }
+
+    /// Formats `args` into the newest entry, overwriting the oldest one if
+    /// full. Nothing is stored if the result doesn't fit in an entry.
+    pub fn push_overwrite_fmt(&mut self, args: fmt::Arguments) -> Result<(), FixedStringError> {
+        self.push_overwrite_with(|entry| {
+            fmt::write(entry, args).map_err(|_| FixedStringError::FormatError)
+        })
+    }
+
+    /// Returns a stored entry, where `0` is the oldest
+    pub fn get(&self, index: usize) -> Option<&str> {
+        if index >= self.count {
+            return None;
+        }
+
+        self.entries.get(self.slot(index)).map(FixedString::as_str)
+    }
+
+    /// Iterates over the stored entries, from the oldest to the newest
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator<Item = &str> + '_ {
+        (0..self.count).map(|index| self.get(index).unwrap_or_default())
+    }
+
+    /// Returns the position in `entries` of the entry at `index`
+    fn slot(&self, index: usize) -> usize {
+        self.head
+            .saturating_add(K)
+            .saturating_sub(self.count)
+            .saturating_add(index)
+            .checked_rem(K)
+            .unwrap_or_default()
+    }
+
+    fn push_overwrite_with(
+        &mut self,
+        write: impl FnOnce(&mut FixedString<N>) -> Result<(), FixedStringError>,
+    ) -> Result<(), FixedStringError> {
+        if K == 0 {
+            return Ok(());
+        }
+
+        let mut entry = FixedString::new();
+        write(&mut entry)?;
+        if let Some(slot) = self.entries.get_mut(self.head) {
+            *slot = entry;
+        }
+        self.head = self
+            .head
+            .saturating_add(1)
+            .checked_rem(K)
+            .unwrap_or_default();
+        self.count = self.count.saturating_add(1).min(K);
+
+        Ok(())
+    }
+}
+
+impl<const N: usize, const K: usize> Default for FixedStringRingBuffer<N, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,123 @@
+use core::{error, fmt, result::Result, str};
+
+use rkyv::{
+    Archive, Deserialize, Place, Portable, Serialize,
+    bytecheck::CheckBytes,
+    munge::munge,
+    primitive::ArchivedUsize,
+    rancor::{Fallible, Source},
+};
+
+use crate::{CHARACTER, CHARACTER_NONE, FixedString};
+
+/// Archived representation of a `FixedString`. Has a fixed size and can be
+/// accessed directly from the archive bytes without any copying.
+#[derive(Portable)]
+#[rkyv(crate = rkyv)]
+#[repr(C)]
+pub struct ArchivedFixedString<const N: usize> {
+    length: ArchivedUsize,
+    buffer: [CHARACTER; N],
+}
+
+impl<const N: usize> ArchivedFixedString<N> {
+    /// Returns a `&str` representation of the archived string
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(self.units()) }
+    }
+
+    /// Returns the code units of the archived string
+    fn units(&self) -> &[CHARACTER] {
+        self.buffer.get(..self.length()).unwrap_or_default()
+    }
+
+    /// Returns the length of the archived string
+    pub fn length(&self) -> usize {
+        self.length.to_native() as usize
+    }
+
+    /// Returns the capacity of the archived string
+    pub fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> fmt::Debug for ArchivedFixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Display for ArchivedFixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl<const N: usize> PartialEq<FixedString<N>> for ArchivedFixedString<N> {
+    fn eq(&self, other: &FixedString<N>) -> bool {
+        self.units() == other.as_units()
+    }
+}
+
+impl<const N: usize> Archive for FixedString<N> {
+    type Archived = ArchivedFixedString<N>;
+    type Resolver = ();
+
+    fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedFixedString { length, buffer } = out);
+        length.write(ArchivedUsize::from_native(self.length as _));
+
+        let mut units = [CHARACTER_NONE; N];
+        if let Some(units) = units.get_mut(..self.length) {
+            units.copy_from_slice(self.as_units());
+        }
+        buffer.write(units);
+    }
+}
+
+impl<S: Fallible + ?Sized, const N: usize> Serialize<S> for FixedString<N> {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized, const N: usize> Deserialize<FixedString<N>, D>
+    for ArchivedFixedString<N>
+{
+    fn deserialize(&self, _: &mut D) -> Result<FixedString<N>, D::Error> {
+        let mut fixed_string = FixedString::new();
+        let units = self.units();
+        let _ = fixed_string.write_units(0, units);
+        fixed_string.length = units.len();
+
+        Ok(fixed_string)
+    }
+}
+
+/// Raised when validating an archived string that is longer than its capacity
+/// or isn't valid UTF-8
+#[derive(Debug)]
+struct InvalidArchivedFixedString;
+
+impl fmt::Display for InvalidArchivedFixedString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid archived fixed string")
+    }
+}
+
+impl error::Error for InvalidArchivedFixedString {}
+
+unsafe impl<C: Fallible + ?Sized, const N: usize> CheckBytes<C> for ArchivedFixedString<N>
+where
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+        let value = unsafe { &*value };
+        let length = value.length.to_native() as usize;
+        match value.buffer.get(..length) {
+            Some(units) if str::from_utf8(units).is_ok() => Ok(()),
+            _ => Err(C::Error::new(InvalidArchivedFixedString)),
+        }
+    }
+}
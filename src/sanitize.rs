@@ -0,0 +1,116 @@
+use core::{
+    option::Option::{self, Some},
+    result::Result::{self, Err, Ok},
+    str,
+};
+
+use crate::{FixedString, FixedStringError, LengthType, Utf8, copy_within_checked};
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Trims whitespace from both ends and squeezes every run of whitespace
+    /// in between into a single space, in place
+    pub fn collapse_whitespace(&mut self) {
+        let units = self.as_units_mut();
+        let mut length = 0;
+        let mut read = 0;
+        let mut pending_space = false;
+        while let Some(character) = next_char(units, read) {
+            let width = character.len_utf8();
+            if character.is_whitespace() {
+                pending_space = length > 0;
+            } else {
+                if pending_space {
+                    if let Some(unit) = units.get_mut(length) {
+                        *unit = b' ';
+                    }
+                    length = length.saturating_add(1);
+                    pending_space = false;
+                }
+                copy_within_checked(units, read..read.saturating_add(width), length);
+                length = length.saturating_add(width);
+            }
+            read = read.saturating_add(width);
+        }
+        self.length = L::from_usize(length);
+    }
+
+    /// Replaces every control character, including tabs and line breaks,
+    /// with `replacement`. Nothing is changed if the content grows beyond the
+    /// capacity.
+    pub fn sanitize_control_chars(&mut self, replacement: char) -> Result<(), FixedStringError> {
+        let mut encoded = [0; 4];
+        let encoded = replacement.encode_utf8(&mut encoded).as_bytes();
+        let length = self.length();
+
+        // An ASCII replacement never takes more room than the character it
+        // replaces, so the content can be rewritten front to back
+        if let [replacement] = *encoded {
+            let units = self.as_units_mut();
+            let mut write = 0;
+            let mut read = 0;
+            while let Some(character) = next_char(units, read) {
+                let width = character.len_utf8();
+                if character.is_control() {
+                    if let Some(unit) = units.get_mut(write) {
+                        *unit = replacement;
+                    }
+                    write = write.saturating_add(1);
+                } else {
+                    copy_within_checked(units, read..read.saturating_add(width), write);
+                    write = write.saturating_add(width);
+                }
+                read = read.saturating_add(width);
+            }
+            self.length = L::from_usize(write);
+            return Ok(());
+        }
+
+        // Otherwise it never takes less, so it is rewritten back to front
+        let new_length = self
+            .chars()
+            .map(|character| {
+                if character.is_control() {
+                    encoded.len()
+                } else {
+                    character.len_utf8()
+                }
+            })
+            .sum::<usize>();
+        if new_length > N {
+            return Err(FixedStringError::Overflow {
+                requested: new_length.saturating_sub(length),
+                available: N.saturating_sub(length),
+            });
+        }
+
+        let mut write = new_length;
+        let mut read = length;
+        while read > 0 {
+            let units = self.as_units().get(..read).unwrap_or_default();
+            let character = unsafe { str::from_utf8_unchecked(units) }
+                .chars()
+                .next_back()
+                .unwrap_or_default();
+            let width = character.len_utf8();
+            read = read.saturating_sub(width);
+            if character.is_control() {
+                write = write.saturating_sub(encoded.len());
+                self.write_units(write, encoded)?;
+            } else {
+                write = write.saturating_sub(width);
+                let mut bytes = [0; 4];
+                self.write_units(write, character.encode_utf8(&mut bytes).as_bytes())?;
+            }
+        }
+        self.length = L::from_usize(new_length);
+
+        Ok(())
+    }
+}
+
+/// Returns the character starting at byte `index` of UTF-8 `units`
+fn next_char(units: &[u8], index: usize) -> Option<char> {
+    unsafe { str::from_utf8_unchecked(units.get(index..)?) }
+        .chars()
+        .next()
+}
@@ -0,0 +1,102 @@
+use core::{
+    iter::Iterator,
+    option::Option::Some,
+    result::Result::{self, Err, Ok},
+};
+
+use crate::FixedStringError;
+
+/// A field extracted by `scan`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanValue<'a> {
+    Int(i64),
+    Uint(u64),
+    Str(&'a str),
+    Char(char),
+}
+
+/// Matches all of `text` against `format`, writing each field to `values` in
+/// order and returning how many were written
+pub(crate) fn scan<'a>(
+    mut text: &'a str,
+    mut format: &str,
+    values: &mut [ScanValue<'a>],
+) -> Result<usize, FixedStringError> {
+    let mut count = 0;
+    while let Some(percent) = format.find('%') {
+        let (literal, rest) = format.split_at_checked(percent).unwrap_or_default();
+        text = text
+            .strip_prefix(literal)
+            .ok_or(FixedStringError::ParseError)?;
+        let mut rest = rest.strip_prefix('%').unwrap_or_default().chars();
+        let conversion = rest.next().ok_or(FixedStringError::ParseError)?;
+        format = rest.as_str();
+
+        let (value, length) = match conversion {
+            '%' if text.starts_with('%') => {
+                text = text.get(1..).unwrap_or_default();
+                continue;
+            }
+            'd' | 'i' => {
+                let sign = text.starts_with(['-', '+']) as usize;
+                let length = sign.saturating_add(digits(text.get(sign..).unwrap_or_default(), 10)?);
+                let value = text.get(..length).unwrap_or_default().parse();
+                (ScanValue::Int(parse(value)?), length)
+            }
+            'u' => {
+                let length = digits(text, 10)?;
+                let value = text.get(..length).unwrap_or_default().parse();
+                (ScanValue::Uint(parse(value)?), length)
+            }
+            'x' | 'X' => {
+                let length = digits(text, 16)?;
+                let digits = text.get(..length).unwrap_or_default();
+                let value = parse(u64::from_str_radix(digits, 16))?;
+                (ScanValue::Uint(value), length)
+            }
+            'c' => {
+                let character = text.chars().next().ok_or(FixedStringError::ParseError)?;
+                (ScanValue::Char(character), character.len_utf8())
+            }
+            's' => {
+                let literal = format.split('%').next().unwrap_or_default();
+                let length = match literal {
+                    "" if format.is_empty() => text.len(),
+                    "" => text.find(char::is_whitespace).unwrap_or(text.len()),
+                    literal => text.find(literal).ok_or(FixedStringError::ParseError)?,
+                };
+                (
+                    ScanValue::Str(text.get(..length).unwrap_or_default()),
+                    length,
+                )
+            }
+            _ => return Err(FixedStringError::ParseError),
+        };
+
+        *values.get_mut(count).ok_or(FixedStringError::ParseError)? = value;
+        count = count.saturating_add(1);
+        text = text.get(length..).unwrap_or_default();
+    }
+
+    if text != format {
+        return Err(FixedStringError::ParseError);
+    }
+    Ok(count)
+}
+
+/// Returns the length of the run of digits at the start of `text`, which
+/// mustn't be empty
+fn digits(text: &str, radix: u32) -> Result<usize, FixedStringError> {
+    match text
+        .bytes()
+        .take_while(|byte| (*byte as char).is_digit(radix))
+        .count()
+    {
+        0 => Err(FixedStringError::ParseError),
+        length => Ok(length),
+    }
+}
+
+fn parse<T, E>(result: Result<T, E>) -> Result<T, FixedStringError> {
+    result.map_err(|_| FixedStringError::ParseError)
+}
@@ -0,0 +1,41 @@
+use core::{
+    iter::Iterator,
+    option::Option::{self, Some},
+};
+
+/// Returns the byte index of the first occurrence of `needle` in `haystack`,
+/// comparing ASCII letters case-insensitively
+pub(crate) fn find_ignore_ascii_case(haystack: &str, needle: &str) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+
+    // A match can't start inside a character, as `needle` starts with a
+    // leading byte and other bytes are compared exactly
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+#[cfg(feature = "memchr")]
+pub(crate) use crate::memchr_impl::{count_char, find, find_char};
+
+/// Returns the byte index of the first occurrence of `needle` in `haystack`
+#[cfg(not(feature = "memchr"))]
+pub(crate) fn find(haystack: &str, needle: &str) -> Option<usize> {
+    haystack.find(needle)
+}
+
+/// Returns the byte index of the first occurrence of `character` in
+/// `haystack`
+#[cfg(not(feature = "memchr"))]
+pub(crate) fn find_char(haystack: &str, character: char) -> Option<usize> {
+    haystack.find(character)
+}
+
+/// Returns the number of occurrences of `character` in `haystack`
+#[cfg(not(feature = "memchr"))]
+pub(crate) fn count_char(haystack: &str, character: char) -> usize {
+    haystack.matches(character).count()
+}
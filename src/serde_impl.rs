@@ -0,0 +1,52 @@
+use core::{fmt, result::Result};
+
+use serde::{
+    de::{self, Deserialize, Deserializer, Visitor},
+    ser::{Serialize, Serializer},
+};
+
+use crate::{FixedString, FixedStringView};
+
+/// Serialize a `FixedString` as a regular string
+impl<const N: usize> Serialize for FixedString<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Deserialize a `FixedString`, failing if the string doesn't fit the capacity
+impl<'de, const N: usize> Deserialize<'de> for FixedString<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FixedStringVisitor::<N> { truncate: false })
+    }
+}
+
+/// Deserialize a `FixedString`, truncating strings that don't fit the
+/// capacity at a character boundary instead of failing. Use it with
+/// `#[serde(deserialize_with = "fixed_string::deserialize_truncating")]`.
+pub fn deserialize_truncating<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<FixedString<N>, D::Error> {
+    deserializer.deserialize_str(FixedStringVisitor::<N> { truncate: true })
+}
+
+struct FixedStringVisitor<const N: usize> {
+    /// Whether strings that don't fit are truncated instead of rejected
+    truncate: bool,
+}
+
+impl<const N: usize> Visitor<'_> for FixedStringVisitor<N> {
+    type Value = FixedString<N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string of at most {} bytes", N)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let value = match self.truncate {
+            true => value.get(..value.floor_char_boundary(N)).unwrap_or(value),
+            false => value,
+        };
+        FixedString::new_with(value).map_err(|_| E::invalid_length(value.len(), &self))
+    }
+}
@@ -0,0 +1,25 @@
+use core::result::Result::{self, Err, Ok};
+
+use crate::{FixedString, FixedStringError, FixedStringView, LengthType, Utf8};
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Truncates the content at byte `at` and returns the rest as a new
+    /// `FixedString` of capacity `M`, e.g. to separate a parsed header from
+    /// its payload. `at` has to be on a character boundary. Nothing changes
+    /// if the rest doesn't fit in `M`.
+    pub fn split_off<const M: usize>(
+        &mut self,
+        at: usize,
+    ) -> Result<FixedString<M, Utf8, L>, FixedStringError> {
+        if !self.is_char_boundary(at) {
+            return Err(FixedStringError::InvalidIndex {
+                index: at,
+                length: self.length(),
+            });
+        }
+
+        let tail = FixedString::new_with(self.as_str().get(at..).unwrap_or_default())?;
+        self.length = L::from_usize(at);
+        Ok(tail)
+    }
+}
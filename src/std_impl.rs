@@ -0,0 +1,18 @@
+use std::io;
+
+use crate::{FixedString, FixedStringError};
+
+/// Writes UTF-8 encoded bytes into the `FixedString`. If the buffer doesn't
+/// fit, as many whole characters as possible are written.
+impl<const N: usize> io::Write for FixedString<N> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_utf8(buf).map_err(|err| match err {
+            FixedStringError::InvalidUtf8 => io::Error::new(io::ErrorKind::InvalidData, err),
+            _ => io::Error::other(err),
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use core::{
+    ops::FnMut,
+    option::Option::Some,
+    result::Result::{self, Err},
+};
+
+use crate::{FixedString, FixedStringError, LengthType, TextEncoding};
+
+impl<const N: usize, E: TextEncoding, L: LengthType> FixedString<N, E, L> {
+    /// Appends `template` with every `{name}` placeholder replaced by what
+    /// `substitute` writes for `name`, e.g. `Device {id} online`. `{{` and
+    /// `}}` are written as literal braces. An unclosed or unmatched brace is
+    /// rejected with a `FormatError`. Nothing is appended if any part fails.
+    ///
+    /// The template is rendered into a separate `FixedString`, which is what
+    /// `substitute` gets, so it can't change the existing content.
+    pub fn render_template<F>(
+        &mut self,
+        template: &str,
+        substitute: F,
+    ) -> Result<(), FixedStringError>
+    where
+        F: FnMut(&str, &mut Self) -> Result<(), FixedStringError>,
+    {
+        let mut rendered = Self::new();
+        rendered.push_template(template, substitute)?;
+        self.push_units(rendered.as_units())
+    }
+
+    fn push_template<F>(
+        &mut self,
+        mut template: &str,
+        mut substitute: F,
+    ) -> Result<(), FixedStringError>
+    where
+        F: FnMut(&str, &mut Self) -> Result<(), FixedStringError>,
+    {
+        while let Some(brace) = template.find(['{', '}']) {
+            let (literal, rest) = template.split_at_checked(brace).unwrap_or_default();
+            self.push(literal)?;
+
+            if let Some(tail) = rest.strip_prefix("{{") {
+                self.push("{")?;
+                template = tail;
+            } else if let Some(tail) = rest.strip_prefix("}}") {
+                self.push("}")?;
+                template = tail;
+            } else if let Some(placeholder) = rest.strip_prefix('{') {
+                let (name, tail) = match placeholder.find(['{', '}']) {
+                    Some(end) => placeholder.split_at_checked(end).unwrap_or_default(),
+                    None => return Err(FixedStringError::FormatError),
+                };
+                let Some(tail) = tail.strip_prefix('}') else {
+                    return Err(FixedStringError::FormatError);
+                };
+                substitute(name, self)?;
+                template = tail;
+            } else {
+                return Err(FixedStringError::FormatError);
+            }
+        }
+
+        self.push(template)
+    }
+}
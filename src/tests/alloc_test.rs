@@ -0,0 +1,32 @@
+use alloc::{borrow::Cow, string::String};
+
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn to_string() {
+    let fixed_string = FixedString::<12>::new_with("Hello World!").unwrap();
+    let string = String::from(fixed_string);
+    assert_eq!("Hello World!", string);
+}
+
+#[test]
+fn from_string() {
+    let fixed_string = FixedString::<12>::try_from(String::from("Hello World!")).unwrap();
+    assert_eq!("Hello World!", fixed_string.as_str());
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 12,
+            available: 11
+        }),
+        FixedString::<11>::try_from(String::from("Hello World!"))
+    );
+}
+
+#[test]
+fn into_cow() {
+    let fixed_string = FixedString::<12>::new_with("Hello World!").unwrap();
+    let cow: Cow<str> = (&fixed_string).into();
+    assert!(matches!(cow, Cow::Borrowed("Hello World!")));
+    assert_eq!("Hello World!", fixed_string.into_cow());
+}
@@ -0,0 +1,36 @@
+use arbitrary::{Arbitrary, Unstructured};
+
+use crate::{FixedString, FixedStringView, Utf8};
+
+#[test]
+fn arbitrary() {
+    let data = [
+        0xff, 0x68, 0xc3, 0xa9, 0x6c, 0x6c, 0x6f, 0xe2, 0x82, 0xac, 0x80, 0x41,
+    ];
+    let mut u = Unstructured::new(&data[1..]);
+    let fixed_string = FixedString::<6>::arbitrary(&mut u).unwrap();
+    assert_eq!("héllo", fixed_string.as_str());
+
+    let fixed_string =
+        FixedString::<16, Utf8, u8>::arbitrary_take_rest(Unstructured::new(&data[1..])).unwrap();
+    assert_eq!("héllo€", fixed_string.as_str());
+    let fixed_string =
+        FixedString::<4>::arbitrary_take_rest(Unstructured::new(&data[1..])).unwrap();
+    assert_eq!("hél", fixed_string.as_str());
+    let fixed_string = FixedString::<4>::arbitrary_take_rest(Unstructured::new(&[])).unwrap();
+    assert_eq!("", fixed_string.as_str());
+}
+
+#[test]
+fn arbitrary_is_valid() {
+    for seed in 0..64u8 {
+        let data: [u8; 32] =
+            core::array::from_fn(|i| (i as u8).wrapping_mul(seed).wrapping_add(0x7f));
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let fixed_string = FixedString::<8>::arbitrary(&mut u).unwrap();
+            assert!(fixed_string.length() <= 8);
+            assert!(core::str::from_utf8(fixed_string.as_bytes()).is_ok());
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use arrayvec::ArrayString;
+
+use crate::{FixedString, FixedStringView};
+
+#[test]
+fn to_array_string() {
+    let fixed_string = FixedString::<12>::new_with("Hello World!").unwrap();
+    let string: ArrayString<12> = fixed_string.into();
+    assert_eq!("Hello World!", string.as_str());
+}
+
+#[test]
+fn from_array_string() {
+    let string = ArrayString::<12>::from("Hello World!").unwrap();
+    let fixed_string = FixedString::from(string);
+    assert_eq!("Hello World!", fixed_string.as_str());
+}
@@ -0,0 +1,98 @@
+use crate::{Base32Alphabet, FixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn rfc4648() {
+    let cases: [(&[u8], &str); 7] = [
+        (b"", ""),
+        (b"f", "MY======"),
+        (b"fo", "MZXQ===="),
+        (b"foo", "MZXW6==="),
+        (b"foob", "MZXW6YQ="),
+        (b"fooba", "MZXW6YTB"),
+        (b"foobar", "MZXW6YTBOI======"),
+    ];
+    for (bytes, encoded) in cases {
+        let fixed_string = FixedString::<16>::from_base32(bytes, Base32Alphabet::Rfc4648).unwrap();
+        assert_eq!(encoded, fixed_string.as_str());
+
+        let mut out = [0; 8];
+        let length = fixed_string
+            .decode_base32_into(&mut out, Base32Alphabet::Rfc4648)
+            .unwrap();
+        assert_eq!(bytes, &out[..length]);
+    }
+
+    let mut out = [0; 8];
+    let lower = FixedString::<16>::new_with("mzxw6ytb").unwrap();
+    assert_eq!(
+        Ok(5),
+        lower.decode_base32_into(&mut out, Base32Alphabet::Rfc4648)
+    );
+    assert_eq!(b"fooba", &out[..5]);
+}
+
+#[test]
+fn crockford() {
+    let fixed_string =
+        FixedString::<16>::from_base32(&[0xde, 0xad, 0xbe, 0xef], Base32Alphabet::Crockford)
+            .unwrap();
+    assert_eq!("VTPVXVR", fixed_string.as_str());
+
+    let typed = FixedString::<16>::new_with("vtp-vxvr").unwrap();
+    let mut out = [0; 4];
+    assert_eq!(
+        Ok(4),
+        typed.decode_base32_into(&mut out, Base32Alphabet::Crockford)
+    );
+    assert_eq!([0xde, 0xad, 0xbe, 0xef], out);
+
+    let mut out = [0; 1];
+    let ambiguous = FixedString::<16>::new_with("iO").unwrap();
+    assert_eq!(
+        Ok(1),
+        ambiguous.decode_base32_into(&mut out, Base32Alphabet::Crockford)
+    );
+    assert_eq!([0x08], out);
+}
+
+#[test]
+fn errors() {
+    let mut fixed_string = FixedString::<8>::new_with("ab").unwrap();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 8,
+            available: 6
+        }),
+        fixed_string.push_base32(b"f", Base32Alphabet::Rfc4648)
+    );
+    assert_eq!("ab", fixed_string.as_str());
+
+    let mut out = [0; 8];
+    assert_eq!(
+        Err(FixedStringError::InvalidBase32 { index: 2 }),
+        FixedString::<16>::new_with("MZ1W6YTB")
+            .unwrap()
+            .decode_base32_into(&mut out, Base32Alphabet::Rfc4648)
+    );
+    assert_eq!(
+        Err(FixedStringError::InvalidBase32 { index: 7 }),
+        FixedString::<16>::new_with("MZXW6YT")
+            .unwrap()
+            .decode_base32_into(&mut out, Base32Alphabet::Rfc4648)
+    );
+    assert_eq!(
+        Err(FixedStringError::InvalidBase32 { index: 3 }),
+        FixedString::<16>::new_with("MZX")
+            .unwrap()
+            .decode_base32_into(&mut out, Base32Alphabet::Crockford)
+    );
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 5,
+            available: 4
+        }),
+        FixedString::<16>::new_with("MZXW6YTB")
+            .unwrap()
+            .decode_base32_into(&mut out[..4], Base32Alphabet::Rfc4648)
+    );
+}
@@ -0,0 +1,50 @@
+use core::fmt::Write;
+
+use crate::{CFixedString, FixedStringError};
+
+#[test]
+fn check_default_parameters() {
+    let c_fixed_string = CFixedString::<13>::new_with("Hello World!").unwrap();
+
+    assert_eq!(12, c_fixed_string.capacity());
+    assert_eq!(12, c_fixed_string.length());
+    assert!(c_fixed_string.is_full());
+    assert_eq!(c"Hello World!", c_fixed_string.as_c_str());
+}
+
+#[test]
+fn trailing_nul() {
+    let mut c_fixed_string = CFixedString::<16>::new();
+    assert_eq!(c"", c_fixed_string.as_c_str());
+
+    write!(c_fixed_string, "Hello {}", 42).unwrap();
+    let c_str = unsafe { core::ffi::CStr::from_ptr(c_fixed_string.as_ptr()) };
+    assert_eq!(c"Hello 42", c_str);
+
+    c_fixed_string.clear();
+    assert_eq!(c"", c_fixed_string.as_c_str());
+}
+
+#[test]
+fn from_c_str() {
+    let c_fixed_string = CFixedString::<13>::from_c_str(c"Hello World!").unwrap();
+    assert_eq!("Hello World!", c_fixed_string.as_str());
+}
+
+#[test]
+fn reject_invalid() {
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 12,
+            available: 11
+        }),
+        CFixedString::<12>::new_with("Hello World!")
+    );
+
+    let mut c_fixed_string = CFixedString::<16>::new_with("Hello").unwrap();
+    assert_eq!(
+        Err(FixedStringError::InteriorNul { index: 6 }),
+        c_fixed_string.push(" \0World!")
+    );
+    assert_eq!(c"Hello", c_fixed_string.as_c_str());
+}
@@ -0,0 +1,70 @@
+use crate::{FixedString, FixedStringError, FixedStringView, Utf8};
+
+#[test]
+fn make_ascii_titlecase() {
+    let mut fixed_string = FixedString::<32>::new_with("motor_SPEED-2nd gräß ümlaut").unwrap();
+    fixed_string.make_ascii_titlecase();
+    assert_eq!("Motor_Speed-2nd Gräß ümlaut", fixed_string.as_str());
+
+    let mut fixed_string = FixedString::<8>::new();
+    fixed_string.make_ascii_titlecase();
+    assert_eq!("", fixed_string.as_str());
+}
+
+#[test]
+fn to_snake_case() {
+    let cases = [
+        ("motorSpeed", "motor_speed"),
+        ("HTTPServer", "http_server"),
+        ("sensor-ID 2", "sensor_id_2"),
+        ("__max__rpmLimit", "max_rpm_limit"),
+        ("größeWert", "größe_wert"),
+        ("", ""),
+    ];
+    for (input, expected) in cases {
+        let fixed_string = FixedString::<16>::new_with(input).unwrap();
+        assert_eq!(
+            expected,
+            fixed_string.to_snake_case::<16>().unwrap().as_str()
+        );
+    }
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 11,
+            available: 8
+        }),
+        FixedString::<16>::new_with("motorSpeed")
+            .unwrap()
+            .to_snake_case::<8>()
+    );
+}
+
+#[test]
+fn to_camel_case() {
+    let cases = [
+        ("motor_speed", "motorSpeed"),
+        ("HTTP-server", "httpServer"),
+        ("Max RPM limit", "maxRpmLimit"),
+        ("motorSpeed", "motorSpeed"),
+        ("größe_wert", "größeWert"),
+        ("", ""),
+    ];
+    for (input, expected) in cases {
+        let fixed_string = FixedString::<16, Utf8, u8>::new_with(input).unwrap();
+        assert_eq!(
+            expected,
+            fixed_string.to_camel_case::<16>().unwrap().as_str()
+        );
+    }
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 10,
+            available: 8
+        }),
+        FixedString::<16>::new_with("motor_speed")
+            .unwrap()
+            .to_camel_case::<8>()
+    );
+}
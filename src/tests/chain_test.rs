@@ -0,0 +1,58 @@
+use core::fmt::Write;
+
+use crate::{FixedString, FixedStringChain, FixedStringError, FixedStringView};
+
+#[test]
+fn write_across_pieces() {
+    let mut pieces = [FixedString::<8>::new(); 3];
+    let mut chain = FixedStringChain::new(&mut pieces);
+    write!(chain, "GET /status HTTP/1.1").unwrap();
+
+    assert_eq!(20, chain.length());
+    assert_eq!(24, chain.capacity());
+
+    let mut output = FixedString::<32>::new();
+    write!(output, "{}", chain).unwrap();
+    assert_eq!("GET /status HTTP/1.1", output.as_str());
+    assert_eq!(3, chain.pieces().count());
+    assert_eq!(Some('1'), chain.chars().last());
+
+    let pieces = chain.into_inner();
+    assert_eq!("GET /sta", pieces[0].as_str());
+    assert_eq!("tus HTTP", pieces[1].as_str());
+    assert_eq!("/1.1", pieces[2].as_str());
+}
+
+#[test]
+fn characters_are_not_split() {
+    let mut pieces = [FixedString::<4>::new(); 2];
+    let mut chain = FixedStringChain::new(&mut pieces);
+    chain.push("abcö").unwrap();
+    chain.push_char('d').unwrap();
+
+    let pieces = chain.into_inner();
+    assert_eq!("abc", pieces[0].as_str());
+    assert_eq!("öd", pieces[1].as_str());
+}
+
+#[test]
+fn overflow_rolls_back() {
+    let mut pieces = [FixedString::<4>::new(); 2];
+    let mut chain = FixedStringChain::new(&mut pieces);
+    chain.push("abc").unwrap();
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 6,
+            available: 5
+        }),
+        chain.push("defghi")
+    );
+    assert_eq!(3, chain.length());
+
+    chain.push("defgh").unwrap();
+    assert!(chain.push("i").is_err());
+
+    chain.clear();
+    assert!(chain.is_empty());
+}
@@ -0,0 +1,44 @@
+use core::fmt::Write;
+
+use crate::{ChunkedWriter, FixedString, FixedStringView};
+
+#[test]
+fn flush_full_chunks() {
+    let mut output = FixedString::<64>::new();
+    let mut flushes = 0;
+    let mut writer = ChunkedWriter::<8, _, ()>::new(|chunk: &str| {
+        flushes += 1;
+        output.push(chunk).map_err(|_| ())?;
+        output.push("|").map_err(|_| ())
+    });
+
+    write!(writer, "temperature={}, humidity={}", 21, 40).unwrap();
+    assert_eq!("=40", writer.pending());
+    writer.finish().unwrap();
+
+    assert_eq!(4, flushes);
+    assert_eq!("temperat|ure=21, |humidity|=40|", output.as_str());
+}
+
+#[test]
+fn characters_are_not_split() {
+    let mut output = FixedString::<32>::new();
+    let mut writer = ChunkedWriter::<4, _, ()>::new(|chunk: &str| {
+        output.push(chunk).unwrap();
+        output.push("|").map_err(|_| ())
+    });
+
+    writer.push("abcö").unwrap();
+    writer.finish().unwrap();
+    assert_eq!("abc|ö|", output.as_str());
+}
+
+#[test]
+fn callback_error() {
+    let mut writer = ChunkedWriter::<4, _, u8>::new(|_: &str| Err(7));
+
+    writer.push("abc").unwrap();
+    assert!(write!(writer, "defg").is_err());
+    assert_eq!(Some(7), writer.take_error());
+    assert_eq!(None, writer.take_error());
+}
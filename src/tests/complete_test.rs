@@ -0,0 +1,35 @@
+use crate::{FixedString, FixedStringView, common_prefix};
+
+const COMMANDS: [&str; 5] = ["reboot", "reset", "restore", "status", "stats"];
+
+#[test]
+fn common_prefix_of() {
+    assert_eq!("re", common_prefix(&COMMANDS[..3]));
+    assert_eq!("stat", common_prefix(&COMMANDS[3..]));
+    assert_eq!("", common_prefix(&COMMANDS));
+    assert_eq!("reset", common_prefix(&["reset"]));
+    assert_eq!("", common_prefix(&[]));
+    assert_eq!("rö", common_prefix(&["röd", "röt", "rösten"]));
+    assert_eq!("r", common_prefix(&["rö", "rä"]));
+    assert_eq!("ab", common_prefix(&["abc", "ab"]));
+}
+
+#[test]
+fn complete_from() {
+    let mut typed = FixedString::<16>::new_with("reb").unwrap();
+    assert_eq!(Some("oot"), typed.complete_from(&COMMANDS));
+
+    typed.clear();
+    typed.push("res").unwrap();
+    assert_eq!(Some(""), typed.complete_from(&COMMANDS));
+
+    typed.clear();
+    typed.push("sta").unwrap();
+    let completion = typed.complete_from(&COMMANDS).unwrap();
+    typed.push(completion).unwrap();
+    assert_eq!("stat", typed.as_str());
+
+    typed.clear();
+    typed.push("x").unwrap();
+    assert_eq!(None, typed.complete_from(&COMMANDS));
+}
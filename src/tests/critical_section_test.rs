@@ -0,0 +1,35 @@
+use core::fmt::Write;
+
+use crate::{FixedStringError, FixedStringView, StaticFixedString};
+
+static STATUS: StaticFixedString<32> = StaticFixedString::new();
+
+#[test]
+fn shared_status() {
+    STATUS.store("idle").unwrap();
+    assert_eq!("idle", STATUS.load().unwrap().as_str());
+
+    let length = STATUS
+        .with(|status| {
+            status.clear();
+            write!(status, "sampling {} Hz", 100).unwrap();
+            status.length()
+        })
+        .unwrap();
+    assert_eq!(15, length);
+    assert_eq!("sampling 100 Hz", STATUS.load().unwrap().as_str());
+
+    assert!(STATUS.store("a status line that is far too long").is_err());
+    assert_eq!("sampling 100 Hz", STATUS.load().unwrap().as_str());
+
+    STATUS.clear().unwrap();
+    assert_eq!(0, STATUS.load().unwrap().length());
+}
+
+#[test]
+fn reentrant_with() {
+    let status = StaticFixedString::<8>::new();
+    let inner = status.with(|_| status.load()).unwrap();
+    assert_eq!(Err(FixedStringError::Busy), inner);
+    assert!(status.load().is_ok());
+}
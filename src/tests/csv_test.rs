@@ -0,0 +1,67 @@
+use crate::{CsvFields, FixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn fields() {
+    let line = FixedString::<64>::new_with("1,\"a,\"\"b\"\"\",,\"\",x y").unwrap();
+    let mut fields = line.csv_fields();
+
+    let field = fields.next().unwrap();
+    assert_eq!(Some("1"), field.as_str());
+    assert!(!field.is_quoted());
+
+    let field = fields.next().unwrap();
+    assert_eq!("a,\"\"b\"\"", field.raw());
+    assert_eq!(None, field.as_str());
+    assert_eq!("a,\"b\"", field.to_fixed_string::<8>().unwrap().as_str());
+
+    assert_eq!(Some(""), fields.next().unwrap().as_str());
+    assert_eq!(Some(""), fields.next().unwrap().as_str());
+    assert_eq!(Some("x y"), fields.next().unwrap().as_str());
+    assert_eq!(None, fields.next());
+}
+
+#[test]
+fn fields_edge_cases() {
+    assert_eq!(0, CsvFields::new("", ',').count());
+    assert_eq!(2, CsvFields::new("a,", ',').count());
+
+    let mut fields = CsvFields::new("\"unterminated;x", ';');
+    assert_eq!("unterminated;x", fields.next().unwrap().raw());
+    assert_eq!(None, fields.next());
+
+    let mut fields = CsvFields::new("a;\"b\";c", ';');
+    assert_eq!(Some("a"), fields.next().unwrap().as_str());
+    assert_eq!(Some("b"), fields.next().unwrap().as_str());
+    assert_eq!(Some("c"), fields.next().unwrap().as_str());
+}
+
+#[test]
+fn push_csv_field() {
+    let mut fixed_string = FixedString::<64>::new();
+    fixed_string
+        .push_csv_record(&["12.5", "say \"hi\"", "a,b", "line\nbreak"])
+        .unwrap();
+    assert_eq!(
+        "12.5,\"say \"\"hi\"\"\",\"a,b\",\"line\nbreak\"",
+        fixed_string.as_str()
+    );
+
+    let mut parsed = [FixedString::<16>::new(); 4];
+    for (field, parsed) in fixed_string.csv_fields().zip(parsed.iter_mut()) {
+        *parsed = field.to_fixed_string().unwrap();
+    }
+    assert_eq!("say \"hi\"", parsed[1].as_str());
+}
+
+#[test]
+fn push_csv_record_overflow() {
+    let mut fixed_string = FixedString::<8>::new_with("x").unwrap();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 5,
+            available: 4
+        }),
+        fixed_string.push_csv_record(&["ab", "\"c"])
+    );
+    assert_eq!("x", fixed_string.as_str());
+}
@@ -0,0 +1,33 @@
+use crate::{Cursor, FixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn parse_command() {
+    let command = FixedString::<32>::new_with("SET  led=12 ö").unwrap();
+    let mut cursor = command.cursor();
+    assert_eq!(
+        "SET",
+        cursor.take_while(|character| character.is_ascii_uppercase())
+    );
+    cursor.skip_whitespace();
+    assert_eq!(5, cursor.position());
+    assert_eq!("led", cursor.take_while(char::is_alphabetic));
+    cursor.expect("=").unwrap();
+    assert_eq!(Ok(12), cursor.parse_u32());
+    assert_eq!(Some(' '), cursor.next_char());
+    assert_eq!(Some('ö'), cursor.peek());
+    assert_eq!(Some('ö'), cursor.next_char());
+    assert!(cursor.is_at_end());
+    assert_eq!(None, cursor.next_char());
+    assert_eq!("", cursor.rest());
+}
+
+#[test]
+fn failed_reads() {
+    let mut cursor = Cursor::new("x=4294967296");
+    assert_eq!(Err(FixedStringError::ParseError), cursor.expect("y"));
+    assert_eq!(Err(FixedStringError::ParseError), cursor.parse_u32());
+    cursor.expect("x=").unwrap();
+    assert_eq!(Err(FixedStringError::ParseError), cursor.parse_u32());
+    assert_eq!("4294967296", cursor.rest());
+    assert_eq!("", cursor.take_while(char::is_alphabetic));
+}
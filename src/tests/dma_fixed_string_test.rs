@@ -0,0 +1,56 @@
+use core::mem::{align_of, size_of};
+
+use crate::{DMA_ALIGNMENT, DmaFixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn alignment() {
+    let dma_string = DmaFixedString::<64>::new();
+
+    assert_eq!(DMA_ALIGNMENT, align_of::<DmaFixedString<64>>());
+    assert_eq!(0, size_of::<DmaFixedString<64>>() % DMA_ALIGNMENT);
+    assert_eq!(0, dma_string.as_ptr() as usize % DMA_ALIGNMENT);
+}
+
+#[test]
+fn receive() {
+    let mut dma_string = DmaFixedString::<32>::new();
+    let dma_slice = dma_string.as_dma_slice();
+    assert_eq!(32, dma_slice.len());
+
+    dma_slice[..12].copy_from_slice(b"Hello World!");
+    dma_string.commit(12).unwrap();
+    assert_eq!("Hello World!", dma_string.as_str());
+
+    dma_string.push("!").unwrap();
+    assert_eq!(13, dma_string.length());
+}
+
+#[test]
+fn commit_invalid() {
+    let mut dma_string = DmaFixedString::<32>::new();
+    dma_string.as_dma_slice()[..2].copy_from_slice(&[0xc3, 0x28]);
+
+    assert_eq!(Err(FixedStringError::InvalidUtf8), dma_string.commit(2));
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 33,
+            available: 32
+        }),
+        dma_string.commit(33)
+    );
+}
+
+#[test]
+fn mutate() {
+    let mut dma_string = DmaFixedString::<32>::new();
+    dma_string.push("AT+CSQ").unwrap();
+    dma_string.push_char('?').unwrap();
+    core::fmt::Write::write_fmt(&mut dma_string, format_args!("={}", 12)).unwrap();
+    assert_eq!("AT+CSQ?=12", dma_string.as_str());
+
+    dma_string.truncate(6).unwrap();
+    assert_eq!("AT+CSQ", dma_string.as_str());
+    dma_string.clear();
+    assert_eq!(0, dma_string.length());
+    assert_eq!(32, dma_string.as_dma_slice().len());
+}
@@ -0,0 +1,37 @@
+use core::fmt::Write;
+
+use crate::{DoubleBufferedFixedString, FixedStringView};
+
+#[test]
+fn swap_publishes() {
+    let mut buffer = DoubleBufferedFixedString::<32>::new();
+    let (mut writer, mut reader) = buffer.split();
+
+    write!(writer.back(), "rpm={}", 1200).unwrap();
+    assert_eq!("", reader.load().as_str());
+
+    assert!(writer.swap());
+    assert_eq!("rpm=1200", reader.load().as_str());
+    assert_eq!("", writer.back().as_str());
+
+    writer.back().push("rpm=1250").unwrap();
+    assert!(writer.swap());
+    assert_eq!("rpm=1250", reader.load().as_str());
+}
+
+#[test]
+fn no_swap_while_reading() {
+    let mut buffer = DoubleBufferedFixedString::<32>::new();
+    let (mut writer, mut reader) = buffer.split();
+    writer.back().push("first").unwrap();
+    writer.swap();
+
+    writer.back().push("second").unwrap();
+    let (swapped, seen) = reader.read(|front| (writer.swap(), front.length()));
+    assert!(!swapped);
+    assert_eq!(5, seen);
+    assert_eq!("second", writer.back().as_str());
+
+    assert!(writer.swap());
+    assert_eq!("second", reader.load().as_str());
+}
@@ -0,0 +1,42 @@
+use core::time::Duration;
+
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+fn format(duration: Duration, decimals: u8) -> FixedString<32> {
+    let mut fixed_string = FixedString::new();
+    fixed_string.push_duration(duration, decimals).unwrap();
+    fixed_string
+}
+
+#[test]
+fn push_duration() {
+    assert_eq!("00:00:00", format(Duration::ZERO, 0).as_str());
+    assert_eq!("01:01:05", format(Duration::from_secs(3665), 0).as_str());
+    assert_eq!(
+        "1d 02:03:04.567",
+        format(Duration::from_millis(93_784_567), 3).as_str()
+    );
+    assert_eq!(
+        "12d 00:00:59.9",
+        format(Duration::new(12 * 86_400 + 59, 999_999_999), 1).as_str()
+    );
+    assert_eq!(
+        "00:00:00.000000001",
+        format(Duration::from_nanos(1), 9).as_str()
+    );
+}
+
+#[test]
+fn duration_errors() {
+    let mut fixed_string = FixedString::<8>::new();
+    assert_eq!(
+        Err(FixedStringError::FormatError),
+        fixed_string.push_duration(Duration::ZERO, 10)
+    );
+    assert!(
+        fixed_string
+            .push_duration(Duration::from_secs(86_400), 0)
+            .is_err()
+    );
+    assert_eq!("", fixed_string.as_str());
+}
@@ -0,0 +1,52 @@
+use embedded_io::{BufRead, Error, ErrorKind, Read, Write};
+
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn write() {
+    let mut fixed_string = FixedString::<12>::new();
+    fixed_string.write_all(b"Hello World!").unwrap();
+    assert_eq!("Hello World!", fixed_string.as_str());
+
+    let err = fixed_string.write(b"!").unwrap_err();
+    assert_eq!(ErrorKind::WriteZero, err.kind());
+}
+
+#[test]
+fn write_invalid_utf8() {
+    let mut fixed_string = FixedString::<12>::new();
+    assert_eq!(
+        Err(FixedStringError::InvalidUtf8),
+        fixed_string.write(&[0xc3, 0x28])
+    );
+}
+
+#[test]
+fn read() {
+    let mut fixed_string = FixedString::<12>::new_with("Hello World!").unwrap();
+    let mut buf = [0u8; 6];
+
+    assert_eq!(6, fixed_string.read(&mut buf).unwrap());
+    assert_eq!(b"Hello ", &buf);
+    assert_eq!("World!", fixed_string.as_str());
+}
+
+#[test]
+fn read_whole_characters() {
+    let mut fixed_string = FixedString::<12>::new_with("aöb").unwrap();
+    let mut buf = [0u8; 2];
+
+    assert_eq!(1, fixed_string.read(&mut buf).unwrap());
+    assert_eq!(2, fixed_string.read(&mut buf).unwrap());
+    assert_eq!("ö".as_bytes(), &buf);
+    assert_eq!("b", fixed_string.as_str());
+}
+
+#[test]
+fn buf_read() {
+    let mut fixed_string = FixedString::<12>::new_with("Hello World!").unwrap();
+
+    assert_eq!(b"Hello World!", fixed_string.fill_buf().unwrap());
+    fixed_string.consume(6);
+    assert_eq!(b"World!", fixed_string.fill_buf().unwrap());
+}
@@ -0,0 +1,46 @@
+use crate::{FixedString, FixedStringView, Utf16, Utf32};
+
+#[test]
+fn utf32() {
+    let mut fixed_string = FixedString::<4, Utf32>::new_with("Hö").unwrap();
+    fixed_string.push_char('🦀').unwrap();
+
+    assert_eq!(&[0x48, 0xf6, 0x1f980], fixed_string.as_units());
+    assert_eq!(3, fixed_string.length());
+}
+
+#[test]
+fn transcode() {
+    let fixed_string = FixedString::<8>::new_with("Hö🦀").unwrap();
+
+    let wide_string = fixed_string.transcode::<4, Utf16>().unwrap();
+    assert_eq!(4, wide_string.length());
+
+    let narrow_string = wide_string.transcode::<8, _>().unwrap();
+    assert_eq!(fixed_string, narrow_string);
+    assert_eq!("Hö🦀", narrow_string.as_str());
+
+    assert!(fixed_string.transcode::<2, Utf32>().is_err());
+}
+
+#[test]
+fn format_wide() {
+    let wide_string = FixedString::<16, Utf16>::format(format_args!("{}-{}", 1, "ö")).unwrap();
+    assert_eq!(&[0x31, 0x2d, 0xf6], wide_string.as_units());
+}
+
+#[test]
+fn display_padding_wide() {
+    let wide_string = FixedString::<8, Utf16>::new_with("Hö🦀").unwrap();
+    let mut output = FixedString::<64>::new();
+    core::fmt::write(
+        &mut output,
+        format_args!(
+            "[{:>5}][{:-<5}][{:^6.2}]",
+            wide_string, wide_string, wide_string
+        ),
+    )
+    .unwrap();
+
+    assert_eq!("[  Hö🦀][Hö🦀--][  Hö  ]", output.as_str());
+}
@@ -0,0 +1,39 @@
+use crate::{FixedString, FixedStringError, FixedStringView, Utf16};
+
+#[test]
+fn escape_default() {
+    let mut fixed_string = FixedString::<32>::new();
+    fixed_string.push_escape_default("a\"b\n\tö").unwrap();
+    assert_eq!("a\\\"b\\n\\t\\u{f6}", fixed_string.as_str());
+}
+
+#[test]
+fn escape_debug() {
+    let mut fixed_string = FixedString::<32>::new();
+    fixed_string.push_escape_debug("ö\r\0").unwrap();
+    assert_eq!("ö\\r\\0", fixed_string.as_str());
+}
+
+#[test]
+fn escape_ascii() {
+    let mut fixed_string = FixedString::<32>::new_with("rx: ").unwrap();
+    fixed_string.push_escape_ascii(b"AT\r\n\x02\xff").unwrap();
+    assert_eq!("rx: AT\\r\\n\\x02\\xff", fixed_string.as_str());
+
+    let mut wide_string = FixedString::<4, Utf16>::new();
+    wide_string.push_escape_ascii(b"\x7f").unwrap();
+    assert_eq!(4, wide_string.length());
+}
+
+#[test]
+fn escape_overflow() {
+    let mut fixed_string = FixedString::<8>::new_with("ab").unwrap();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 8,
+            available: 6
+        }),
+        fixed_string.push_escape_ascii(b"\x00\x01")
+    );
+    assert_eq!("ab", fixed_string.as_str());
+}
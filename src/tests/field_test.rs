@@ -0,0 +1,62 @@
+use crate::{FixedString, FixedStringError, FixedStringView, FixedWideString};
+
+#[test]
+fn push_field() {
+    let mut record = FixedString::<64>::new();
+    record.push_field_left("PUMP", 6, ' ').unwrap();
+    record.push_field_right("42", 5, '0').unwrap();
+    record.push_field_right("-42", 5, '0').unwrap();
+    record.push_field_right("+7", 4, ' ').unwrap();
+    record.push_field_left("grüße", 3, '.').unwrap();
+    record.push_field_left("ö", 3, '·').unwrap();
+    record.push_field_left("ignored", 0, ' ').unwrap();
+    assert_eq!("PUMP  00042-0042  +7grüö··", record.as_str());
+
+    let mut wide_string = FixedWideString::<8>::new();
+    wide_string.push_field_right("ab", 4, '*').unwrap();
+    assert_eq!(4, wide_string.length());
+}
+
+#[test]
+fn push_field_overflow() {
+    let mut record = FixedString::<8>::new_with("ID").unwrap();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 7,
+            available: 6
+        }),
+        record.push_field_right("1", 4, '·')
+    );
+    assert_eq!("ID", record.as_str());
+}
+
+#[test]
+fn field() {
+    let record = FixedString::<64>::new_with("0042 PUMP   ö  3.50").unwrap();
+    assert_eq!(Ok("0042"), record.field(0..4));
+    assert_eq!(Ok("PUMP"), record.field(4..12));
+    assert_eq!(Ok("ö"), record.field(12..15));
+    assert_eq!(Ok("3.50"), record.field(15..30));
+    assert_eq!(Ok(""), record.field(40..48));
+    assert_eq!(
+        Err(FixedStringError::InvalidIndex {
+            index: 13,
+            length: 20
+        }),
+        record.field(13..15)
+    );
+    assert_eq!(
+        Err(FixedStringError::InvalidIndex {
+            index: 13,
+            length: 20
+        }),
+        record.field(10..13)
+    );
+
+    assert_eq!(
+        Ok(["0042", "PUMP", "3.50", ""]),
+        record.fields(&[(0, 4), (4, 8), (15, 6), (22, 2)])
+    );
+    let fixed_str = crate::FixedStr::new(b"AB  12").unwrap();
+    assert_eq!(Ok(["AB", "12"]), fixed_str.fields(&[(0, 3), (3, 3)]));
+}
@@ -0,0 +1,57 @@
+use crate::{FixedBytes, FixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn check_default_parameters() {
+    let fixed_bytes = FixedBytes::<8>::from_slice(&[0x00, 0xff, 0x10]).unwrap();
+
+    assert_eq!(8, fixed_bytes.capacity());
+    assert_eq!(3, fixed_bytes.length());
+    assert_eq!(&[0x00, 0xff, 0x10], fixed_bytes.as_bytes());
+}
+
+#[test]
+fn push_and_concat() {
+    let mut fixed_bytes = FixedBytes::<4>::new();
+    fixed_bytes.push_byte(0xaa).unwrap();
+    fixed_bytes
+        .concat(&FixedBytes::<2>::from_slice(&[0xbb, 0xcc]).unwrap())
+        .unwrap();
+
+    assert_eq!(&[0xaa, 0xbb, 0xcc], fixed_bytes.as_bytes());
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 2,
+            available: 1
+        }),
+        fixed_bytes.extend_from_slice(&[0xdd, 0xee])
+    );
+}
+
+#[test]
+fn convert() {
+    let fixed_string = FixedString::<12>::new_with("Hello World!").unwrap();
+    let fixed_bytes = FixedBytes::from(&fixed_string);
+    assert_eq!(b"Hello World!", fixed_bytes.as_bytes());
+
+    let converted = FixedString::try_from(&fixed_bytes).unwrap();
+    assert_eq!("Hello World!", converted.as_str());
+
+    let invalid = FixedBytes::<2>::from_slice(&[0xc3, 0x28]).unwrap();
+    assert_eq!(
+        Err(FixedStringError::InvalidUtf8),
+        FixedString::try_from(&invalid)
+    );
+}
+
+#[test]
+fn modify_in_place() {
+    let mut fixed_bytes = FixedBytes::<8>::from_slice(&[0x3b, 0x2e, 0x2c, 0x2c, 0x2f]).unwrap();
+    fixed_bytes.iter_mut().for_each(|byte| *byte ^= 0x5a);
+    assert_eq!(
+        "atvvu",
+        FixedString::<8>::try_from(&fixed_bytes).unwrap().as_str()
+    );
+
+    fixed_bytes.as_mut_bytes()[0] = b'A';
+    assert_eq!(b"Atvvu", fixed_bytes.as_bytes());
+}
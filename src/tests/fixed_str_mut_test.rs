@@ -0,0 +1,77 @@
+use core::fmt::Write;
+
+use crate::{FixedStrMut, FixedString, FixedStringError, FixedStringRef, FixedStringView};
+
+#[test]
+fn check_default_parameters() {
+    let mut buffer = [0u8; 16];
+    let mut fixed_str = FixedStrMut::new(&mut buffer);
+    fixed_str.assign("Hello World!").unwrap();
+
+    assert_eq!(16, fixed_str.capacity());
+    assert_eq!(12, fixed_str.length());
+    assert_eq!("Hello World!", fixed_str.as_str());
+    assert_eq!(b"Hello World!", &fixed_str.into_inner()[..12]);
+}
+
+#[test]
+fn with_length() {
+    let mut buffer = *b"Hello World!\0\0\0\0";
+    let mut fixed_str = FixedStrMut::with_length(&mut buffer, 5).unwrap();
+    write!(fixed_str, " {}", 42).unwrap();
+    assert_eq!("Hello 42", fixed_str.as_str());
+
+    let mut buffer = [0xc3, 0x28];
+    assert_eq!(
+        Err(FixedStringError::InvalidUtf8),
+        FixedStrMut::with_length(&mut buffer, 2).map(|_| ())
+    );
+}
+
+#[test]
+fn as_dyn() {
+    let mut buffer = [0u8; 12];
+    let mut fixed_str = FixedStrMut::new(&mut buffer);
+    let fixed_string = FixedString::<6>::new_with("World!").unwrap();
+
+    let string_ref: &mut dyn FixedStringRef = &mut fixed_str;
+    string_ref.push("Hello ").unwrap();
+    string_ref.concatenate(&fixed_string).unwrap();
+    assert_eq!("Hello World!", string_ref.as_str());
+    assert!(string_ref.is_full());
+    assert!(string_ref.push_char('!').is_err());
+}
+
+#[test]
+fn truncate() {
+    let mut buffer = [0u8; 8];
+    let mut fixed_str = FixedStrMut::new(&mut buffer);
+    fixed_str.push("häj").unwrap();
+
+    assert!(fixed_str.truncate(2).is_err());
+    fixed_str.truncate(1).unwrap();
+    assert_eq!("h", fixed_str.as_str());
+    assert_eq!(7, fixed_str.remaining_capacity());
+}
+
+#[test]
+fn get_checks_capacity() {
+    let mut buffer = [0u8; 8];
+    let mut fixed_str = FixedStrMut::new(&mut buffer);
+    fixed_str.push("Hi").unwrap();
+    let fixed_string = FixedString::<8>::new_with("Hi").unwrap();
+
+    for view in [&fixed_str as &dyn FixedStringView, &fixed_string] {
+        assert_eq!(Ok(&0), view.get(2));
+        assert_eq!(
+            Err(FixedStringError::InvalidIndex {
+                index: 8,
+                length: 8
+            }),
+            view.get(8)
+        );
+    }
+
+    *fixed_str.get_mut(7).unwrap() = b'!';
+    assert_eq!(b'!', fixed_str.into_inner()[7]);
+}
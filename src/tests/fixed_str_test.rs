@@ -0,0 +1,67 @@
+use crate::{FixedStr, FixedStringError, FixedStringView};
+
+#[test]
+fn check_default_parameters() {
+    let payload = b"Hello World!";
+    let fixed_str = FixedStr::new(payload).unwrap();
+
+    assert_eq!(12, fixed_str.capacity());
+    assert_eq!(12, fixed_str.length());
+    assert!(fixed_str.is_full());
+    assert_eq!("Hello World!", fixed_str.as_str());
+    assert_eq!(b'W', *fixed_str.get(6).unwrap());
+}
+
+#[test]
+fn with_length() {
+    let frame = *b"Hello\0\0\0";
+    let fixed_str = FixedStr::with_length(&frame, 5).unwrap();
+    assert_eq!("Hello", fixed_str.as_str());
+    assert_eq!(Ok(&0), fixed_str.get(5));
+    assert_eq!(
+        Err(FixedStringError::InvalidIndex {
+            index: 8,
+            length: 8
+        }),
+        fixed_str.get(8)
+    );
+}
+
+#[test]
+fn validation() {
+    assert_eq!(
+        Err(FixedStringError::InvalidUtf8),
+        FixedStr::new(&[0xc3, 0x28]).map(|_| ())
+    );
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 3,
+            available: 2
+        }),
+        FixedStr::with_length(b"Hi", 3).map(|_| ())
+    );
+}
+
+#[test]
+fn read_only_trait_object() {
+    fn describe(string: &dyn FixedStringView) -> (usize, bool) {
+        (string.length(), string.starts_with("OK"))
+    }
+
+    let fixed_str = FixedStr::new(b"OK 42").unwrap();
+    let fixed_string = crate::FixedString::<8>::new_with("ERROR").unwrap();
+    assert_eq!((5, true), describe(&fixed_str));
+    assert_eq!((5, false), describe(&fixed_string));
+    assert_eq!(Ok(&b'4'), FixedStringView::get(&fixed_str, 3));
+}
+
+#[test]
+fn alternate_debug() {
+    let fixed_str = FixedStr::new(b"a b\0").unwrap();
+    let mut output = crate::FixedString::<64>::new();
+    core::fmt::write(&mut output, format_args!("{:#?}", fixed_str)).unwrap();
+    assert_eq!(
+        "FixedStr { capacity: 4, len: 4, \"a b\\0\" }",
+        output.as_str()
+    );
+}
@@ -1,4 +1,6 @@
-use crate::{FixedString, FixedStringRef};
+use core::error::Error;
+
+use crate::{FixedString, FixedStringError, FixedStringRef, FixedStringView};
 
 #[test]
 fn check_default_paramteters() {
@@ -16,6 +18,7 @@ fn check_format() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn concatinate() {
     let mut fixed_string1 = FixedString::<12>::new();
     let mut fixed_string2 = FixedString::<6>::new();
@@ -26,6 +29,30 @@ fn concatinate() {
     assert_eq!("Hello World!", fixed_string1.as_str());
 }
 
+#[test]
+fn concatenate() {
+    let mut fixed_string1 = FixedString::<12>::new();
+    let mut fixed_string2 = FixedString::<6>::new();
+    fixed_string1.assign("Hello ").unwrap();
+    fixed_string2.assign("World!").unwrap();
+
+    fixed_string1.concatenate(&fixed_string2).unwrap();
+    assert_eq!("Hello World!", fixed_string1.as_str());
+}
+
+#[test]
+fn concat() {
+    let mut fixed_string = FixedString::<16>::new();
+    fixed_string.concat("Hello").unwrap();
+    fixed_string
+        .concat(FixedString::<8>::new_with(" World").unwrap())
+        .unwrap();
+    fixed_string
+        .concat(crate::FixedStr::new(b"!").unwrap())
+        .unwrap();
+    assert_eq!("Hello World!", fixed_string.as_str());
+}
+
 #[test]
 #[should_panic]
 fn double_assign() {
@@ -55,8 +82,27 @@ fn format_overflow() {
     FixedString::<11>::format(format_args!("Hello {}", "World!")).unwrap();
 }
 
+#[test]
+fn from_display() {
+    let address = core::net::Ipv4Addr::new(192, 168, 1, 20);
+    let fixed_string = FixedString::<16>::from_display(&address).unwrap();
+    assert_eq!("192.168.1.20", fixed_string.as_str());
+
+    let fixed_string = FixedString::<4, crate::Utf16>::from_display(&-1.5).unwrap();
+    assert_eq!(4, fixed_string.length());
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 12,
+            available: 8
+        }),
+        FixedString::<8>::from_display(&address)
+    );
+}
+
 #[test]
 #[should_panic]
+#[allow(deprecated)]
 fn concatinate_overflow() {
     let mut fixed_string1 = FixedString::<11>::new();
     let mut fixed_string2 = FixedString::<6>::new();
@@ -66,6 +112,17 @@ fn concatinate_overflow() {
     fixed_string1.concatinate(&fixed_string2).unwrap()
 }
 
+#[test]
+#[should_panic]
+fn concatenate_overflow() {
+    let mut fixed_string1 = FixedString::<11>::new();
+    let mut fixed_string2 = FixedString::<6>::new();
+    fixed_string1.assign("Hello ").unwrap();
+    fixed_string2.assign("World!").unwrap();
+
+    fixed_string1.concatenate(&fixed_string2).unwrap()
+}
+
 #[test]
 fn from_raw() {
     let raw = ['H', 'e', 'l', 'l', 'o', ' ', 'W', 'o', 'r', 'l', 'd', '!'];
@@ -74,6 +131,26 @@ fn from_raw() {
     assert_eq!("Hello World!", fixed_string.as_str());
 }
 
+#[test]
+fn from_raw_with_len() {
+    let raw = *b"key\0value\0\0\0";
+    let fixed_string = FixedString::from_raw_with_len(&raw, 10).unwrap();
+    assert_eq!("key\0value\0", fixed_string.as_str());
+    assert_eq!("key", FixedString::from_raw(&raw).unwrap().as_str());
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 13,
+            available: 12
+        }),
+        FixedString::from_raw_with_len(&raw, 13)
+    );
+    assert_eq!(
+        Err(FixedStringError::InvalidUtf8),
+        FixedString::from_raw_with_len(&[0xc3, 0x28], 2)
+    );
+}
+
 #[test]
 fn to_raw() {
     let mut fixed_string = FixedString::<128>::new();
@@ -88,6 +165,7 @@ fn to_raw() {
 }
 
 #[test]
+#[allow(clippy::explicit_counter_loop)]
 fn iter() {
     let mut fixed_string = FixedString::<128>::new();
     fixed_string.assign("Hello World!").unwrap();
@@ -102,3 +180,403 @@ fn iter() {
         counter += 1;
     }
 }
+
+#[test]
+fn error_display() {
+    let error: &dyn Error = &FixedStringError::AlreadyAssigned;
+    let fixed_string = FixedString::<64>::format(format_args!("{}", error)).unwrap();
+    assert_eq!("string is already assigned", fixed_string.as_str());
+}
+
+#[test]
+fn error_context() {
+    let mut fixed_string = FixedString::<11>::new();
+    fixed_string.assign("Hello ").unwrap();
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 6,
+            available: 5
+        }),
+        fixed_string.push("World!")
+    );
+    assert_eq!(Ok(&0), fixed_string.get(6));
+    assert_eq!(
+        Err(FixedStringError::InvalidIndex {
+            index: 11,
+            length: 11
+        }),
+        fixed_string.get(11)
+    );
+}
+
+#[test]
+fn as_c_str() {
+    let mut fixed_string = FixedString::<13>::new_with("Hello World!").unwrap();
+    assert_eq!(c"Hello World!", fixed_string.as_c_str().unwrap());
+}
+
+#[test]
+fn as_c_str_invalid() {
+    let mut fixed_string = FixedString::<12>::new_with("Hello World!").unwrap();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 1,
+            available: 0
+        }),
+        fixed_string.as_c_str()
+    );
+
+    let mut fixed_string = FixedString::<13>::new_with("Hello\0World!").unwrap();
+    assert_eq!(
+        Err(FixedStringError::InteriorNul { index: 5 }),
+        fixed_string.as_c_str()
+    );
+}
+
+#[test]
+fn raw_parts() {
+    let source = "Hello World!";
+    let fixed_string =
+        unsafe { FixedString::<16>::from_raw_parts(source.as_ptr(), source.len()) }.unwrap();
+    assert_eq!("Hello World!", fixed_string.as_str());
+    assert_eq!(fixed_string.as_units().as_ptr(), fixed_string.as_ptr());
+
+    let result = unsafe { FixedString::<11>::from_raw_parts(source.as_ptr(), source.len()) };
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 12,
+            available: 11
+        }),
+        result
+    );
+}
+
+#[test]
+fn fill_through_pointer() {
+    let mut fixed_string = FixedString::<16>::new();
+    unsafe { fixed_string.as_mut_ptr().write_bytes(b'a', 4) };
+    assert_eq!(b"aaaa", unsafe {
+        core::slice::from_raw_parts(fixed_string.as_ptr(), 4)
+    });
+}
+
+#[test]
+fn raw_initializes_unused() {
+    let fixed_string = FixedString::<4096>::new_with("Hello").unwrap();
+    let shared = &fixed_string;
+    let raw = shared.raw();
+
+    assert_eq!(b"Hello", &raw[..5]);
+    assert!(raw[5..].iter().all(|character| *character == 0));
+}
+
+#[test]
+fn constant_time_equality() {
+    let mut fixed_string = FixedString::<16>::new_with("1234").unwrap();
+
+    assert!(fixed_string.ct_eq(&FixedString::<16>::new_with("1234").unwrap()));
+    assert!(!fixed_string.ct_eq(&FixedString::<16>::new_with("1235").unwrap()));
+    assert!(!fixed_string.ct_eq(&FixedString::<16>::new_with("123").unwrap()));
+    assert!(!fixed_string.ct_eq(&FixedString::<16>::new_with("12345").unwrap()));
+
+    // The capacities and length types don't have to match
+    assert!(fixed_string.ct_eq(&FixedString::<4, crate::Utf8, u8>::new_with("1234").unwrap()));
+    assert!(!fixed_string.ct_eq(&FixedString::<32>::new_with("12340").unwrap()));
+
+    // Stale content after the length doesn't take part in the comparison
+    fixed_string.clear();
+    fixed_string.push("12").unwrap();
+    assert!(fixed_string.ct_eq(&FixedString::<16>::new_with("12").unwrap()));
+    assert!(FixedString::<8>::new().ct_eq(&FixedString::<0>::new()));
+}
+
+#[test]
+fn ordering() {
+    let apple = FixedString::<16>::new_with("apple").unwrap();
+    let banana = FixedString::<16>::new_with("banana").unwrap();
+    let app = FixedString::<16>::new_with("app").unwrap();
+
+    assert!(apple < banana);
+    assert!(app < apple);
+    assert_eq!(core::cmp::Ordering::Equal, apple.cmp(&apple.clone()));
+}
+
+#[test]
+fn compare_capacities() {
+    let field = FixedString::<8>::new_with("apple").unwrap();
+    let received = FixedString::<64, crate::Utf8, u8>::new_with("apple").unwrap();
+    let banana = FixedString::<32>::new_with("banana").unwrap();
+
+    assert_eq!(field, received);
+    assert_ne!(field, banana);
+    assert!(field < banana);
+    assert!(banana > received);
+    assert_eq!(
+        Some(core::cmp::Ordering::Equal),
+        received.partial_cmp(&field)
+    );
+}
+
+#[test]
+fn copy_into() {
+    let received = FixedString::<64>::new_with("sensor-1").unwrap();
+    let mut field = FixedString::<8, crate::Utf8, u8>::new_with("old").unwrap();
+    received.copy_into(&mut field).unwrap();
+    assert_eq!("sensor-1", field.as_str());
+
+    let mut short = FixedString::<4>::new_with("old").unwrap();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 8,
+            available: 4
+        }),
+        received.copy_into(&mut short)
+    );
+    assert_eq!("old", short.as_str());
+
+    FixedString::<16>::new().copy_into(&mut short).unwrap();
+    assert_eq!("", short.as_str());
+}
+
+#[test]
+fn copy_to_slice() {
+    let fixed_string = FixedString::<16>::new_with("AT+GMR").unwrap();
+    let mut tx = [0xff; 8];
+    assert_eq!(Ok(6), fixed_string.copy_to_slice(&mut tx));
+    assert_eq!(b"AT+GMR\xff\xff", &tx);
+    assert_eq!(Ok(6), fixed_string.copy_to_slice_with_nul(&mut tx));
+    assert_eq!(b"AT+GMR\0\xff", &tx);
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 7,
+            available: 6
+        }),
+        fixed_string.copy_to_slice_with_nul(&mut tx[..6])
+    );
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 6,
+            available: 4
+        }),
+        fixed_string.copy_to_slice(&mut tx[..4])
+    );
+    assert_eq!(b"AT+GMR\0\xff", &tx);
+}
+
+#[test]
+fn to_padded_array() {
+    let fixed_string = FixedString::<16>::new_with("pump-2").unwrap();
+    assert_eq!(Ok(*b"pump-2\0\0"), fixed_string.to_padded_array::<8>(0));
+    assert_eq!(Ok(*b"pump-2  "), fixed_string.to_padded_array(b' '));
+    assert_eq!(Ok(*b"pump-2"), fixed_string.to_padded_array(0));
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 6,
+            available: 4
+        }),
+        fixed_string.to_padded_array::<4>(0)
+    );
+
+    let wide_string = FixedString::<4, crate::Utf16>::new_with("ab").unwrap();
+    assert_eq!(Ok([0x61, 0x62, 0x20]), wide_string.to_padded_array(0x20));
+}
+
+#[test]
+fn secure_erase() {
+    let mut fixed_string = FixedString::<16>::new_with("hunter2").unwrap();
+    fixed_string.secure_erase();
+
+    assert_eq!(0, fixed_string.length());
+    assert_eq!(&[0; 16], unsafe {
+        core::slice::from_raw_parts(fixed_string.as_ptr(), 16)
+    });
+}
+
+#[test]
+fn fill_spare_capacity() {
+    let mut fixed_string = FixedString::<16>::new_with("RX: ").unwrap();
+    let spare = fixed_string.spare_capacity_mut();
+    assert_eq!(12, spare.len());
+
+    spare[..5].copy_from_slice(b"hello");
+    unsafe { fixed_string.set_len(9) };
+    assert_eq!("RX: hello", fixed_string.as_str());
+}
+
+#[test]
+fn push_bytes() {
+    let mut fixed_string = FixedString::<8>::new();
+    fixed_string.push_bytes(b"H\xc3\xa4j").unwrap();
+    assert_eq!("Häj", fixed_string.as_str());
+
+    assert_eq!(
+        Err(FixedStringError::InvalidUtf8),
+        fixed_string.push_bytes(b"\xc3")
+    );
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 5,
+            available: 4
+        }),
+        fixed_string.push_bytes(b"there")
+    );
+    assert_eq!("Häj", fixed_string.as_str());
+}
+
+#[test]
+fn display_padding() {
+    let fixed_string = FixedString::<8>::new_with("Hello").unwrap();
+    let mut output = FixedString::<64>::new();
+    core::fmt::write(
+        &mut output,
+        format_args!(
+            "[{:>7}][{:<7}][{:*^9}][{:.3}]",
+            fixed_string, fixed_string, fixed_string, fixed_string
+        ),
+    )
+    .unwrap();
+
+    assert_eq!("[  Hello][Hello  ][**Hello**][Hel]", output.as_str());
+}
+
+#[test]
+fn hex_and_binary_dump() {
+    let fixed_string = FixedString::<8>::new_with("Hello").unwrap();
+    let wide_string = crate::FixedWideString::<8>::new_with("Hö").unwrap();
+    let mut output = FixedString::<128>::new();
+    core::fmt::write(
+        &mut output,
+        format_args!(
+            "{:x} {:X} {:b} {:x}",
+            fixed_string,
+            fixed_string,
+            FixedString::<2>::new_with("A\n").unwrap(),
+            wide_string
+        ),
+    )
+    .unwrap();
+
+    assert_eq!(
+        "48656c6c6f 48656C6C6F 0100000100001010 004800f6",
+        output.as_str()
+    );
+}
+
+#[test]
+fn insert_and_remove() {
+    let mut fixed_string = FixedString::<8>::new_with("hllö").unwrap();
+    fixed_string.insert(1, 'e').unwrap();
+    assert_eq!("hellö", fixed_string.as_str());
+
+    fixed_string.insert_str(0, "o").unwrap();
+    assert_eq!("ohellö", fixed_string.as_str());
+    assert_eq!(
+        Err(FixedStringError::InvalidIndex {
+            index: 6,
+            length: 7
+        }),
+        fixed_string.insert(6, 'x')
+    );
+
+    assert_eq!(Ok('ö'), fixed_string.remove(5));
+    assert_eq!(Ok('o'), fixed_string.remove(0));
+    assert_eq!("hell", fixed_string.as_str());
+    assert!(fixed_string.remove(4).is_err());
+}
+
+#[test]
+fn trait_operations() {
+    let mut fixed_string = FixedString::<16>::new();
+    let string_ref = fixed_string.get_ref_mut();
+    string_ref.push_bytes(b"AT+CSQ=").unwrap();
+    string_ref.push("12,ö").unwrap();
+
+    assert_eq!(b"AT+CSQ=12,\xc3\xb6", string_ref.as_bytes());
+    assert_eq!(4, string_ref.remaining_capacity());
+    assert!(string_ref.starts_with("AT+"));
+    assert_eq!(Some(6), string_ref.find("="));
+    assert_eq!(None, string_ref.find("OK"));
+    assert_eq!(
+        Err(FixedStringError::InvalidUtf8),
+        string_ref.push_bytes(b"\xff")
+    );
+
+    assert_eq!(
+        Err(FixedStringError::InvalidIndex {
+            index: 11,
+            length: 12
+        }),
+        string_ref.truncate(11)
+    );
+    string_ref.truncate(20).unwrap();
+    string_ref.truncate(6).unwrap();
+    assert_eq!("AT+CSQ", string_ref.as_str());
+}
+
+#[test]
+fn modify_bytes() {
+    let mut fixed_string = FixedString::<8>::new_with("config").unwrap();
+    unsafe { fixed_string.as_mut_bytes() }.make_ascii_uppercase();
+    assert_eq!("CONFIG", fixed_string.as_str());
+}
+
+#[test]
+fn alternate_debug() {
+    let fixed_string = FixedString::<64>::new_with("Hello\nWorld\t\"\u{7}").unwrap();
+    let wide_string = crate::FixedWideString::<8>::new_with("Hö\r").unwrap();
+    let mut output = FixedString::<128>::new();
+    core::fmt::write(
+        &mut output,
+        format_args!("{:?}|{:#?}|{:#?}", wide_string, fixed_string, wide_string),
+    )
+    .unwrap();
+
+    assert_eq!(
+        "Hö\r|FixedString<64> { len: 14, \"Hello\\nWorld\\t\\\"\\u{7}\" }|FixedString<8, Utf16> { len: 3, \"Hö\\r\" }",
+        output.as_str()
+    );
+}
+
+#[test]
+fn alternate_debug_type() {
+    let utf32 = FixedString::<4, crate::Utf32>::new_with("Hö").unwrap();
+    let short = FixedString::<16, crate::Utf8, u8>::new_with("Hi").unwrap();
+    let mut output = FixedString::<128>::new();
+    core::fmt::write(&mut output, format_args!("{:#?}|{:#?}", utf32, short)).unwrap();
+
+    assert_eq!(
+        "FixedString<4, Utf32> { len: 2, \"Hö\" }|FixedString<16, Utf8, u8> { len: 2, \"Hi\" }",
+        output.as_str()
+    );
+}
+
+#[test]
+fn unchecked_push() {
+    let mut fixed_string = FixedString::<16>::new();
+    assert!(fixed_string.remaining_capacity() >= 11);
+    unsafe { fixed_string.push_unchecked("dead") };
+    unsafe {
+        fixed_string.push_char_unchecked('€');
+        fixed_string.concat_unchecked(FixedString::<4>::new_with("ok!").unwrap());
+    }
+    assert_eq!("dead€ok!", fixed_string.as_str());
+
+    let mut wide_string = crate::FixedWideString::<4>::new();
+    unsafe { wide_string.push_unchecked("a😀") };
+    assert_eq!(3, wide_string.length());
+}
+
+#[test]
+fn prelude() {
+    use crate::prelude::*;
+
+    fn greet(string: &mut dyn FixedStringRef) {
+        string.push("Hello").unwrap();
+    }
+
+    let mut fixed_string = crate::FixedString::<8>::new();
+    greet(&mut fixed_string);
+    assert_eq!(5, fixed_string.get_ref().length());
+}
@@ -0,0 +1,98 @@
+use crate::{FixedString, FixedStringError, FixedStringView, FixedWideString};
+
+#[test]
+fn check_default_parameters() {
+    let wide_string = FixedWideString::<16>::new_with("Hello World!").unwrap();
+
+    assert_eq!(16, wide_string.capacity());
+    assert_eq!(12, wide_string.length());
+}
+
+#[test]
+fn surrogate_pairs() {
+    let mut wide_string = FixedWideString::<4>::new_with("ö").unwrap();
+    wide_string.push_char('🦀').unwrap();
+
+    assert_eq!(&[0x00f6, 0xd83e, 0xdd80], wide_string.as_units());
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 2,
+            available: 1
+        }),
+        wide_string.push_char('🦀')
+    );
+}
+
+#[test]
+fn from_utf16() {
+    let wide_string = FixedWideString::<4>::from_utf16(&[0x0048, 0x0069]).unwrap();
+    let fixed_string = FixedString::<4>::format(format_args!("{}", wide_string)).unwrap();
+    assert_eq!("Hi", fixed_string.as_str());
+
+    assert_eq!(
+        Err(FixedStringError::InvalidUtf16),
+        FixedWideString::<4>::from_utf16(&[0xd83e])
+    );
+}
+
+#[test]
+fn convert() {
+    let fixed_string = FixedString::<8>::new_with("Hö🦀").unwrap();
+    let wide_string = FixedWideString::from(&fixed_string);
+    assert_eq!(4, wide_string.length());
+
+    let converted = FixedString::<8>::try_from(&wide_string).unwrap();
+    assert_eq!(fixed_string, converted);
+
+    assert!(FixedString::<4>::try_from(&wide_string).is_err());
+}
+
+#[test]
+fn le_bytes() {
+    let wide_string = FixedWideString::<4>::new_with("Hi").unwrap();
+    let mut out = [0u8; 4];
+
+    assert_eq!(4, wide_string.write_le_bytes(&mut out).unwrap());
+    assert_eq!([b'H', 0, b'i', 0], out);
+    assert!(wide_string.write_le_bytes(&mut out[..3]).is_err());
+}
+
+#[test]
+fn extend_from_utf16() {
+    let mut fixed_string = FixedString::<16>::new_with("id: ").unwrap();
+    fixed_string
+        .extend_from_utf16(&[0x0041, 0x00f6, 0xd83e, 0xdd80])
+        .unwrap();
+    assert_eq!("id: Aö🦀", fixed_string.as_str());
+
+    assert_eq!(
+        Err(FixedStringError::InvalidUtf16),
+        fixed_string.extend_from_utf16(&[0x0041, 0xdd80])
+    );
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 6,
+            available: 5
+        }),
+        fixed_string.extend_from_utf16(&[0x20ac, 0x20ac])
+    );
+    assert_eq!("id: Aö🦀", fixed_string.as_str());
+}
+
+#[test]
+fn extend_from_utf16_lossy() {
+    let mut fixed_string = FixedString::<8>::new();
+    fixed_string
+        .extend_from_utf16_lossy(&[0x0061, 0xd83e, 0x0062])
+        .unwrap();
+    assert_eq!("a\u{fffd}b", fixed_string.as_str());
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 4,
+            available: 3
+        }),
+        fixed_string.extend_from_utf16_lossy(&[0xd83e, 0xdd80])
+    );
+    assert_eq!("a\u{fffd}b", fixed_string.as_str());
+}
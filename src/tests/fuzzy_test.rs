@@ -0,0 +1,26 @@
+use crate::{FixedString, FixedWideString};
+
+#[test]
+fn edit_distance() {
+    let fixed_string = FixedString::<16>::new_with("kitten").unwrap();
+    assert_eq!(3, fixed_string.edit_distance("sitting"));
+    assert_eq!(0, fixed_string.edit_distance("kitten"));
+    assert_eq!(6, fixed_string.edit_distance(""));
+    assert_eq!(4, FixedString::<4>::new().edit_distance("four"));
+    assert_eq!(
+        2,
+        FixedWideString::<8>::new_with("grüße")
+            .unwrap()
+            .edit_distance("grüsse")
+    );
+}
+
+#[test]
+fn closest_match() {
+    let commands = ["reboot", "reset", "status", "help"];
+    let typed = FixedString::<16>::new_with("rebot").unwrap();
+    assert_eq!(Some("reboot"), typed.closest_match(&commands, 2));
+
+    let typed = FixedString::<16>::new_with("xyzzy").unwrap();
+    assert_eq!(None, typed.closest_match(&commands, 2));
+}
@@ -0,0 +1,26 @@
+use crate::{FixedString, FixedStringView};
+
+fn matches(text: &str, pattern: &str) -> bool {
+    FixedString::<32>::new_with(text)
+        .unwrap()
+        .matches_glob(pattern)
+}
+
+#[test]
+fn matches_glob() {
+    assert!(matches("sensors/temp/1", "sensors/*/1"));
+    assert!(matches("sensors/temp/1", "sensors/*"));
+    assert!(matches("log.txt", "*.txt"));
+    assert!(matches("log.txt", "l?g.*"));
+    assert!(matches("", "*"));
+    assert!(matches("", ""));
+    assert!(matches("aaab", "*a*b"));
+    assert!(matches("mäp.bin", "m?p.bin"));
+    assert!(matches("abcabd", "*abd"));
+
+    assert!(!matches("log.txt", "*.bin"));
+    assert!(!matches("log.txt", "log"));
+    assert!(!matches("", "?"));
+    assert!(!matches("ab", "a?b"));
+    assert!(!matches("sensors/temp", "sensors/*/1"));
+}
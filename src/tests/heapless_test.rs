@@ -0,0 +1,40 @@
+use crate::{FixedString, FixedStringError, FixedStringRef, FixedStringView};
+
+#[test]
+fn to_heapless() {
+    let fixed_string = FixedString::<12>::new_with("Hello World!").unwrap();
+
+    let string: heapless::String<12> = fixed_string.into();
+    assert_eq!("Hello World!", string.as_str());
+
+    let vec: heapless::Vec<u8, 12> = fixed_string.into();
+    assert_eq!(b"Hello World!", vec.as_slice());
+}
+
+#[test]
+fn from_heapless() {
+    let string = heapless::String::<12>::try_from("Hello World!").unwrap();
+    let fixed_string = FixedString::from(string);
+    assert_eq!("Hello World!", fixed_string.as_str());
+
+    let vec = heapless::Vec::<u8, 12>::from_slice(b"Hello World!").unwrap();
+    let fixed_string = FixedString::try_from(vec).unwrap();
+    assert_eq!("Hello World!", fixed_string.as_str());
+}
+
+#[test]
+fn from_invalid_heapless_vec() {
+    let vec = heapless::Vec::<u8, 12>::from_slice(&[0xc3, 0x28]).unwrap();
+    assert_eq!(
+        Err(FixedStringError::InvalidUtf8),
+        FixedString::try_from(vec)
+    );
+}
+
+#[test]
+fn concat_heapless() {
+    let mut fixed_string = FixedString::<12>::new_with("Hello ").unwrap();
+    let string = heapless::String::<6>::try_from("World!").unwrap();
+    fixed_string.concat(&string).unwrap();
+    assert_eq!("Hello World!", fixed_string.as_str());
+}
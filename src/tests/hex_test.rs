@@ -0,0 +1,121 @@
+use crate::{FixedString, FixedStringError, FixedStringView, HexCase, HexDump, Utf16};
+
+#[test]
+fn push_hex() {
+    let mut fixed_string = FixedString::<16>::new_with("id=").unwrap();
+    fixed_string
+        .push_hex(&[0xde, 0xad, 0x01], HexCase::Lower)
+        .unwrap();
+    assert_eq!("id=dead01", fixed_string.as_str());
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 8,
+            available: 7
+        }),
+        fixed_string.push_hex(&[0; 4], HexCase::Lower)
+    );
+    assert_eq!("id=dead01", fixed_string.as_str());
+}
+
+#[test]
+fn from_hex_bytes() {
+    let fixed_string = FixedString::<4>::from_hex_bytes(&[0xbe, 0xef], HexCase::Upper).unwrap();
+    assert_eq!("BEEF", fixed_string.as_str());
+
+    let wide_string = FixedString::<4, Utf16>::from_hex_bytes(&[0xab], HexCase::Lower).unwrap();
+    assert_eq!(&[0x61, 0x62], wide_string.as_units());
+}
+
+#[test]
+fn long_hex() {
+    let bytes = [0x5a; 100];
+    let fixed_string = FixedString::<200>::from_hex_bytes(&bytes, HexCase::Upper).unwrap();
+    assert_eq!(200, fixed_string.length());
+
+    let mut decoded = [0; 100];
+    assert_eq!(Ok(100), fixed_string.decode_hex_into(&mut decoded));
+    assert_eq!(bytes, decoded);
+}
+
+#[test]
+fn decode_hex_into() {
+    let mut out = [0; 4];
+    let fixed_string = FixedString::<16>::new_with("C0ffee").unwrap();
+    assert_eq!(Ok(3), fixed_string.decode_hex_into(&mut out));
+    assert_eq!([0xc0, 0xff, 0xee], out[..3]);
+
+    assert_eq!(
+        Err(FixedStringError::InvalidHex { index: 3 }),
+        FixedString::<16>::new_with("abc")
+            .unwrap()
+            .decode_hex_into(&mut out)
+    );
+    assert_eq!(
+        Err(FixedStringError::InvalidHex { index: 1 }),
+        FixedString::<16>::new_with("0g")
+            .unwrap()
+            .decode_hex_into(&mut out)
+    );
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 5,
+            available: 4
+        }),
+        FixedString::<16>::new_with("0011223344")
+            .unwrap()
+            .decode_hex_into(&mut out)
+    );
+}
+
+#[test]
+fn push_bytes_hex_delimited() {
+    let mut fixed_string = FixedString::<32>::new();
+    fixed_string
+        .push_bytes_hex_delimited(&[0xaa, 0xbb, 0xcc, 0x0d, 0xee, 0xff], ":", HexCase::Upper)
+        .unwrap();
+    assert_eq!("AA:BB:CC:0D:EE:FF", fixed_string.as_str());
+
+    let mut fixed_string = FixedString::<32>::new();
+    fixed_string
+        .push_bytes_hex_delimited(&[0x00, 0x1b, 0xc5], " - ", HexCase::Lower)
+        .unwrap();
+    fixed_string
+        .push_bytes_hex_delimited(&[], "-", HexCase::Lower)
+        .unwrap();
+    assert_eq!("00 - 1b - c5", fixed_string.as_str());
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 23,
+            available: 20
+        }),
+        fixed_string.push_bytes_hex_delimited(&[0; 8], ":", HexCase::Lower)
+    );
+    assert_eq!("00 - 1b - c5", fixed_string.as_str());
+}
+
+#[test]
+fn hexdump() {
+    let fixed_string = FixedString::<32>::new_with("Hello World!\r\n\tgrüße").unwrap();
+    let mut output = FixedString::<256>::new();
+    core::fmt::write(&mut output, format_args!("{}", fixed_string.hexdump())).unwrap();
+    assert_eq!(
+        "00000000  48 65 6c 6c 6f 20 57 6f  72 6c 64 21 0d 0a 09 67  |Hello World!...g|\n\
+         00000010  72 c3 bc c3 9f 65                                 |r....e|",
+        output.as_str()
+    );
+
+    let mut bytes = crate::FixedBytes::<4>::new();
+    bytes.extend_from_slice(&[0, 0x7f]).unwrap();
+    output.clear();
+    core::fmt::write(&mut output, format_args!("{}", bytes.hexdump())).unwrap();
+    assert_eq!(
+        "00000000  00 7f                                             |..|",
+        output.as_str()
+    );
+
+    output.clear();
+    core::fmt::write(&mut output, format_args!("{}", HexDump::new(&[]))).unwrap();
+    assert_eq!("", output.as_str());
+}
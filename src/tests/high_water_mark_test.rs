@@ -0,0 +1,35 @@
+use crate::{FixedStrMut, FixedString, FixedStringRef, FixedStringView, HighWaterMark};
+
+fn log_reading(buffer: &mut impl FixedStringRef, value: u32) {
+    buffer.clear();
+    write!(buffer, "reading={}", value).unwrap();
+}
+
+#[test]
+fn high_water_mark() {
+    let mut buffer = HighWaterMark::new(FixedString::<32>::new_with("boot").unwrap());
+    assert_eq!(4, buffer.high_water_mark());
+
+    log_reading(&mut buffer, 123456);
+    log_reading(&mut buffer, 7);
+    assert_eq!("reading=7", buffer.as_str());
+    assert_eq!(14, buffer.high_water_mark());
+
+    buffer.reset_high_water_mark();
+    assert_eq!(9, buffer.high_water_mark());
+    buffer.push_char('0').unwrap();
+    buffer.concat("0").unwrap();
+    assert!(buffer.push("too long to fit in the buffer").is_err());
+    assert_eq!(11, buffer.high_water_mark());
+    assert_eq!("reading=700", buffer.into_inner().as_str());
+}
+
+#[test]
+fn wraps_fixed_str_mut() {
+    let mut bytes = [0; 8];
+    let mut buffer = HighWaterMark::new(FixedStrMut::new(&mut bytes));
+    buffer.assign("abc").unwrap();
+    buffer.truncate(1).unwrap();
+    assert_eq!("a", buffer.get_ref().as_str());
+    assert_eq!(3, buffer.high_water_mark());
+}
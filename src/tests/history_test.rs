@@ -0,0 +1,53 @@
+use crate::{FixedString, FixedStringView, History, LineEditor};
+
+#[test]
+fn push_and_get() {
+    let mut history = History::<16, 3>::new();
+    assert!(history.is_empty());
+
+    for line in ["a", "b", "", "b", "c", "d"] {
+        history.push(line).unwrap();
+    }
+
+    assert_eq!(3, history.len());
+    assert_eq!(Some("d"), history.get(0));
+    assert_eq!(Some("b"), history.get(2));
+    assert_eq!(None, history.get(3));
+    assert!(history.push("this line is too long").is_err());
+}
+
+#[test]
+fn navigation() {
+    let mut history = History::<16, 4>::new();
+    assert_eq!(None, history.up());
+
+    history.push("first").unwrap();
+    history.push("second").unwrap();
+
+    assert_eq!(Some("second"), history.up());
+    assert_eq!(Some("first"), history.up());
+    assert_eq!(Some("first"), history.up());
+    assert_eq!(Some("second"), history.down());
+    assert_eq!(None, history.down());
+    assert_eq!(None, history.down());
+
+    history.up();
+    history.reset();
+    assert_eq!(Some("second"), history.up());
+}
+
+#[test]
+fn recall_into_line_editor() {
+    let mut history = History::<16, 4>::new();
+    history.push("status").unwrap();
+
+    let mut editor = LineEditor::<16>::new();
+    let mut echo = FixedString::<64>::new();
+    editor.insert('x', &mut echo).unwrap();
+
+    echo.clear();
+    editor.set_line(history.up().unwrap(), &mut echo).unwrap();
+    assert_eq!("status", editor.as_str());
+    assert_eq!(6, editor.cursor());
+    assert_eq!("\x1b[1Dstatus\x1b[K", echo.as_str());
+}
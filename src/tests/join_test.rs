@@ -0,0 +1,39 @@
+use crate::{FixedString, FixedStringError, FixedStringView, Utf8};
+
+#[test]
+fn join() {
+    let fixed_string = FixedString::<16>::join(["21.5", "22.0", "19.8"], ",").unwrap();
+    assert_eq!("21.5,22.0,19.8", fixed_string.as_str());
+
+    let parts = [FixedString::<4>::new_with("a").unwrap(), FixedString::new()];
+    let fixed_string = FixedString::<8, Utf8, u8>::join(parts.iter(), " | ").unwrap();
+    assert_eq!("a | ", fixed_string.as_str());
+
+    let fixed_string = FixedString::<4>::join(core::iter::empty::<&str>(), ",").unwrap();
+    assert_eq!("", fixed_string.as_str());
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 14,
+            available: 8
+        }),
+        FixedString::<8>::join(["21.5", "22.0", "19.8"], ",")
+    );
+}
+
+#[test]
+fn push_joined() {
+    let mut fixed_string = FixedString::<16>::new_with("T=").unwrap();
+    fixed_string.push_joined(["1", "2"], ";").unwrap();
+    fixed_string.push_joined(["3"], ";").unwrap();
+    assert_eq!("T=1;23", fixed_string.as_str());
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 11,
+            available: 10
+        }),
+        fixed_string.push_joined(["abc", "def", "g"], "; ")
+    );
+    assert_eq!("T=1;23", fixed_string.as_str());
+}
@@ -0,0 +1,48 @@
+use core::mem::size_of;
+
+use crate::{FixedString, FixedStringError, FixedStringView, Utf8, Utf16};
+
+#[test]
+fn compact_size() {
+    assert_eq!(9, size_of::<FixedString<8, Utf8, u8>>());
+    assert_eq!(10, size_of::<FixedString<8, Utf8, u16>>());
+    assert_eq!(34, size_of::<FixedString<16, Utf16, u16>>());
+}
+
+#[test]
+fn compact_push() {
+    let mut fixed_string = FixedString::<8, Utf8, u8>::new_with("Hello").unwrap();
+    fixed_string.push_char('!').unwrap();
+
+    assert_eq!("Hello!", fixed_string.as_str());
+    assert_eq!(6, fixed_string.length());
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 3,
+            available: 2
+        }),
+        fixed_string.push("!!!")
+    );
+
+    fixed_string.push("!!").unwrap();
+    assert!(fixed_string.is_full());
+}
+
+#[test]
+fn compact_full_capacity() {
+    let mut fixed_string = FixedString::<255, Utf8, u8>::new();
+    for _ in 0..255 {
+        fixed_string.push_char('a').unwrap();
+    }
+
+    assert_eq!(255, fixed_string.length());
+    assert!(fixed_string.push_char('a').is_err());
+}
+
+static TABLE: [FixedString<8, Utf8, u8>; 2] = [FixedString::new(), FixedString::new()];
+
+#[test]
+fn compact_static_table() {
+    assert_eq!(18, size_of::<[FixedString<8, Utf8, u8>; 2]>());
+    assert_eq!("", TABLE[0].as_str());
+}
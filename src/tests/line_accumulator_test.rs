@@ -0,0 +1,55 @@
+use crate::{FixedStringView, LineAccumulator, Truncation};
+
+fn feed<const N: usize>(
+    accumulator: &mut LineAccumulator<N>,
+    bytes: &[u8],
+) -> Option<&'static str> {
+    let mut line = None;
+    for byte in bytes {
+        assert!(line.is_none());
+        line = accumulator
+            .push_byte(*byte)
+            .map(|line| match line.as_str() {
+                "" => "",
+                "AT+OK" => "AT+OK",
+                "grüße" => "grüße",
+                "a\u{fffd}b\u{fffd}" => "a\u{fffd}b\u{fffd}",
+                "0123" => "0123",
+                _ => "unexpected",
+            });
+    }
+    line
+}
+
+#[test]
+fn push_byte() {
+    let mut accumulator = LineAccumulator::<8>::default();
+    assert_eq!(None, feed(&mut accumulator, b"AT+"));
+    assert_eq!("AT+", accumulator.as_str());
+    assert_eq!(Some("AT+OK"), feed(&mut accumulator, b"OK\r\n"));
+    assert_eq!(Some(""), feed(&mut accumulator, b"\n"));
+    assert_eq!(None, feed(&mut accumulator, b"a\rb"));
+    assert_eq!("a\rb", accumulator.as_str());
+    accumulator.clear();
+    assert_eq!(Some("grüße"), feed(&mut accumulator, "grüße\n".as_bytes()));
+    assert_eq!(
+        Some("a\u{fffd}b\u{fffd}"),
+        feed(&mut accumulator, b"a\xffb\xc3\n")
+    );
+    assert!(!accumulator.is_truncated());
+}
+
+#[test]
+fn overflow() {
+    let mut truncating = LineAccumulator::<4>::new(Truncation::Truncate);
+    assert_eq!(Some("0123"), feed(&mut truncating, b"0123456\r\n"));
+    assert!(truncating.is_truncated());
+    assert_eq!(
+        Some("AT+OK"),
+        feed(&mut LineAccumulator::<5>::default(), b"AT+OK\r\n")
+    );
+
+    let mut discarding = LineAccumulator::<4>::new(Truncation::Discard);
+    assert_eq!(None, feed(&mut discarding, b"0123456\r\n"));
+    assert_eq!(Some("0123"), feed(&mut discarding, b"0123\r\n"));
+}
@@ -0,0 +1,91 @@
+use crate::{FixedString, FixedStringError, FixedStringView, LineEditor};
+
+fn type_str<const N: usize>(editor: &mut LineEditor<N>, string: &str, echo: &mut FixedString<128>) {
+    for character in string.chars() {
+        editor.insert(character, echo).unwrap();
+    }
+}
+
+#[test]
+fn insert_and_echo() {
+    let mut editor = LineEditor::<16>::new();
+    let mut echo = FixedString::<128>::new();
+    type_str(&mut editor, "hi", &mut echo);
+
+    assert_eq!("hi", editor.as_str());
+    assert_eq!(2, editor.cursor());
+    assert_eq!("h\x1b[Ki\x1b[K", echo.as_str());
+}
+
+#[test]
+fn insert_in_middle() {
+    let mut editor = LineEditor::<16>::new();
+    let mut echo = FixedString::<128>::new();
+    type_str(&mut editor, "hllo", &mut echo);
+
+    echo.clear();
+    editor.home(&mut echo).unwrap();
+    editor.right(&mut echo).unwrap();
+    editor.insert('e', &mut echo).unwrap();
+
+    assert_eq!("hello", editor.as_str());
+    assert_eq!(2, editor.cursor());
+    assert_eq!("\x1b[4D\x1b[1Cello\x1b[K\x1b[3D", echo.as_str());
+}
+
+#[test]
+fn overwrite() {
+    let mut editor = LineEditor::<4>::new();
+    let mut echo = FixedString::<128>::new();
+    type_str(&mut editor, "abcd", &mut echo);
+
+    editor.set_overwrite(true);
+    editor.home(&mut echo).unwrap();
+    editor.insert('ö', &mut echo).unwrap_err();
+    editor.insert('x', &mut echo).unwrap();
+    assert_eq!("xbcd", editor.as_str());
+
+    editor.end(&mut echo).unwrap();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 1,
+            available: 0
+        }),
+        editor.insert('e', &mut echo)
+    );
+}
+
+#[test]
+fn backspace_and_delete() {
+    let mut editor = LineEditor::<16>::new();
+    let mut echo = FixedString::<128>::new();
+    type_str(&mut editor, "häj", &mut echo);
+
+    echo.clear();
+    editor.left(&mut echo).unwrap();
+    editor.backspace(&mut echo).unwrap();
+    assert_eq!("hj", editor.as_str());
+    assert_eq!(1, editor.cursor());
+    assert_eq!("\x1b[1D\x08j\x1b[K\x1b[1D", echo.as_str());
+
+    echo.clear();
+    editor.delete(&mut echo).unwrap();
+    assert_eq!("h", editor.as_str());
+    assert_eq!("\x1b[K", echo.as_str());
+
+    editor.delete(&mut echo).unwrap();
+    editor.home(&mut echo).unwrap();
+    editor.backspace(&mut echo).unwrap();
+    assert_eq!("h", editor.as_str());
+}
+
+#[test]
+fn take_line() {
+    let mut editor = LineEditor::<16>::new();
+    let mut echo = FixedString::<128>::new();
+    type_str(&mut editor, "reset", &mut echo);
+
+    assert_eq!("reset", editor.take_line().as_str());
+    assert_eq!("", editor.as_str());
+    assert_eq!(0, editor.cursor());
+}
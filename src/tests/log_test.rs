@@ -0,0 +1,56 @@
+use log::{Level, LevelFilter, Log, Record};
+
+use crate::{FixedStringError, FixedStringLogger, Truncation};
+
+fn log(logger: &impl Log, level: Level, args: core::fmt::Arguments) {
+    logger.log(&Record::builder().level(level).args(args).build());
+}
+
+#[test]
+fn stores_records() {
+    let logger = FixedStringLogger::<32, 2>::new(LevelFilter::Info, Truncation::Truncate);
+    log(&logger, Level::Info, format_args!("boot"));
+    log(&logger, Level::Debug, format_args!("ignored"));
+    log(&logger, Level::Warn, format_args!("battery at {}%", 12));
+    log(&logger, Level::Error, format_args!("watchdog"));
+
+    logger
+        .with(|entries| {
+            assert!(entries.iter().eq(["WARN battery at 12%", "ERROR watchdog"]));
+        })
+        .unwrap();
+}
+
+#[test]
+fn truncation() {
+    let truncating = FixedStringLogger::<12, 4>::new(LevelFilter::Trace, Truncation::Truncate);
+    let discarding = FixedStringLogger::<12, 4>::new(LevelFilter::Trace, Truncation::Discard);
+    for logger in [&truncating, &discarding] {
+        log(logger, Level::Info, format_args!("short"));
+        log(logger, Level::Info, format_args!("temperature {}°C", 21));
+    }
+
+    truncating
+        .with(|entries| assert!(entries.iter().eq(["INFO short", "INFO tempera"])))
+        .unwrap();
+    discarding
+        .with(|entries| assert!(entries.iter().eq(["INFO short"])))
+        .unwrap();
+}
+
+#[test]
+fn log_inside_with() {
+    let logger = FixedStringLogger::<32, 4>::new(LevelFilter::Info, Truncation::Truncate);
+    log(&logger, Level::Info, format_args!("boot"));
+
+    logger
+        .with(|entries| {
+            log(&logger, Level::Info, format_args!("dropped"));
+            assert!(entries.iter().eq(["INFO boot"]));
+            assert_eq!(Err(FixedStringError::Busy), logger.with(|_| ()));
+        })
+        .unwrap();
+    logger
+        .with(|entries| assert!(entries.iter().eq(["INFO boot"])))
+        .unwrap();
+}
@@ -0,0 +1,21 @@
+use crate::{FixedString, FixedStringSearch, FixedStringView};
+
+#[test]
+fn search() {
+    let mut buffer = FixedString::<256>::new();
+    for _ in 0..20 {
+        buffer.push("+CSQ: 12,0\r\n").unwrap();
+    }
+    buffer.push("grüße OK\r\n").unwrap();
+
+    assert_eq!(Some(240), buffer.find("grüße"));
+    assert_eq!(Some(0), buffer.find(""));
+    assert_eq!(None, buffer.find("ERROR"));
+    assert_eq!(21, buffer.count_char('\n'));
+    assert_eq!(1, buffer.count_char('ü'));
+    assert_eq!(
+        Some(244),
+        buffer.match_indices('ß').next().map(|(index, _)| index)
+    );
+    assert_eq!(20, buffer.matches("CSQ").count());
+}
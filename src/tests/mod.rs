@@ -1 +1,77 @@
+mod base32_test;
+mod c_fixed_string_test;
+mod case_test;
+mod chain_test;
+mod chunked_test;
+mod complete_test;
+mod csv_test;
+mod cursor_test;
+mod dma_fixed_string_test;
+mod double_buffer_test;
+mod duration_test;
+mod encoding_test;
+mod escape_test;
+mod field_test;
+mod fixed_bytes_test;
+mod fixed_str_mut_test;
+mod fixed_str_test;
 mod fixed_string_test;
+mod fixed_wide_string_test;
+mod fuzzy_test;
+mod glob_test;
+mod hex_test;
+mod high_water_mark_test;
+mod history_test;
+mod join_test;
+mod length_type_test;
+mod line_accumulator_test;
+mod line_editor_test;
+mod natural_test;
+mod newline_test;
+mod nmea_test;
+mod numeric_test;
+mod overflow_stats_test;
+mod parse_test;
+mod pattern_test;
+mod percent_test;
+mod pool_test;
+mod printf_test;
+mod query_test;
+mod resize_test;
+mod ring_buffer_test;
+mod sanitize_test;
+mod scan_test;
+mod search_test;
+mod split_test;
+mod template_test;
+mod tokenize_test;
+mod utf8_decoder_test;
+
+#[cfg(feature = "alloc")]
+mod alloc_test;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_test;
+#[cfg(feature = "arrayvec")]
+mod arrayvec_test;
+#[cfg(feature = "critical-section")]
+mod critical_section_test;
+#[cfg(feature = "embedded-io")]
+mod embedded_io_test;
+#[cfg(feature = "heapless")]
+mod heapless_test;
+#[cfg(feature = "log")]
+mod log_test;
+#[cfg(feature = "memchr")]
+mod memchr_test;
+#[cfg(feature = "postcard")]
+mod postcard_test;
+#[cfg(feature = "regex-lite")]
+mod regex_lite_test;
+#[cfg(feature = "rkyv")]
+mod rkyv_test;
+#[cfg(feature = "std")]
+mod std_test;
+#[cfg(feature = "zerocopy")]
+mod zerocopy_test;
+#[cfg(feature = "zeroize")]
+mod zeroize_test;
@@ -0,0 +1,33 @@
+use core::cmp::Ordering;
+
+use crate::{FixedString, FixedStringView};
+
+#[test]
+fn cmp_natural() {
+    let file2 = FixedString::<16>::new_with("file2.txt").unwrap();
+    assert_eq!(Ordering::Less, file2.cmp_natural("file10.txt"));
+    assert_eq!(Ordering::Greater, file2.cmp_natural("file1.txt"));
+    assert_eq!(Ordering::Equal, file2.cmp_natural("file2.txt"));
+    assert_eq!(Ordering::Less, file2.cmp_natural("file2.txt.bak"));
+    assert_eq!(Ordering::Less, file2.cmp_natural("file02.txx"));
+    assert_eq!(Ordering::Less, file2.cmp_natural("file02.txt"));
+    assert_eq!(Ordering::Greater, file2.cmp_natural("file"));
+    assert_eq!(Ordering::Less, file2.cmp_natural("fileA"));
+    assert_eq!(
+        Ordering::Less,
+        FixedString::<32>::new_with("ch 99999999999999999999999")
+            .unwrap()
+            .cmp_natural("ch 100000000000000000000000")
+    );
+}
+
+#[test]
+fn sort_natural() {
+    let mut channels = ["ch10", "ch2", "ch1b", "ch1", "ch01", "Ch3"]
+        .map(|channel| FixedString::<8>::new_with(channel).unwrap());
+    channels.sort_by(|a, b| a.cmp_natural(b.as_str()));
+    assert_eq!(
+        ["Ch3", "ch1", "ch01", "ch1b", "ch2", "ch10"],
+        channels.each_ref().map(|channel| channel.as_str())
+    );
+}
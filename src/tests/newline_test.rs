@@ -0,0 +1,29 @@
+use crate::{FixedString, FixedStringError, FixedStringView, LineEnding};
+
+#[test]
+fn to_lf() {
+    let mut fixed_string = FixedString::<32>::new_with("OK\r\n+CSQ: 12\r\n\r\nö\r").unwrap();
+    fixed_string.normalize_newlines(LineEnding::Lf).unwrap();
+    assert_eq!("OK\n+CSQ: 12\n\nö\r", fixed_string.as_str());
+    fixed_string.normalize_newlines(LineEnding::Lf).unwrap();
+    assert_eq!("OK\n+CSQ: 12\n\nö\r", fixed_string.as_str());
+}
+
+#[test]
+fn to_crlf() {
+    let mut fixed_string = FixedString::<16, crate::Utf8, u8>::new_with("\na\r\nö\n\n").unwrap();
+    fixed_string.normalize_newlines(LineEnding::CrLf).unwrap();
+    assert_eq!("\r\na\r\nö\r\n\r\n", fixed_string.as_str());
+    fixed_string.normalize_newlines(LineEnding::CrLf).unwrap();
+    assert_eq!("\r\na\r\nö\r\n\r\n", fixed_string.as_str());
+
+    let mut fixed_string = FixedString::<8>::new_with("a\nb\nc\n").unwrap();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 3,
+            available: 2
+        }),
+        fixed_string.normalize_newlines(LineEnding::CrLf)
+    );
+    assert_eq!("a\nb\nc\n", fixed_string.as_str());
+}
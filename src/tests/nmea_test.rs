@@ -0,0 +1,66 @@
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn push_nmea_checksum() {
+    let mut fixed_string =
+        FixedString::<48>::new_with("$GPGLL,4916.45,N,12311.12,W,225444,A,").unwrap();
+    fixed_string.push_nmea_checksum().unwrap();
+    assert_eq!(
+        "$GPGLL,4916.45,N,12311.12,W,225444,A,*1D",
+        fixed_string.as_str()
+    );
+
+    let fixed_string = FixedString::<48>::from_nmea_fields(&[
+        "GPGLL", "4916.45", "N", "12311.12", "W", "225444", "A", "",
+    ])
+    .unwrap();
+    assert_eq!(
+        "$GPGLL,4916.45,N,12311.12,W,225444,A,*1D",
+        fixed_string.as_str()
+    );
+
+    assert_eq!(
+        Err(FixedStringError::ParseError),
+        FixedString::<32>::new_with("GPGLL")
+            .unwrap()
+            .push_nmea_checksum()
+    );
+    let mut full = FixedString::<8>::new_with("$GPGLL").unwrap();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 3,
+            available: 2
+        }),
+        full.push_nmea_checksum()
+    );
+    assert_eq!("$GPGLL", full.as_str());
+}
+
+#[test]
+fn verify_nmea_checksum() {
+    let mut fixed_string =
+        FixedString::<64>::new_with("!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5c\r\n").unwrap();
+    assert_eq!(
+        Ok("AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0"),
+        fixed_string.verify_nmea_checksum()
+    );
+    fixed_string.strip_nmea_checksum().unwrap();
+    assert_eq!(
+        "!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0",
+        fixed_string.as_str()
+    );
+
+    for sentence in [
+        "$GPGLL,4916.45*00",
+        "$GPGLL,4916.45",
+        "GPGLL*46",
+        "$GPGLL*4",
+    ] {
+        let mut fixed_string = FixedString::<32>::new_with(sentence).unwrap();
+        assert_eq!(
+            Err(FixedStringError::ParseError),
+            fixed_string.strip_nmea_checksum()
+        );
+        assert_eq!(sentence, fixed_string.as_str());
+    }
+}
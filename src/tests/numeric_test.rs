@@ -0,0 +1,128 @@
+use crate::{FixedString, FixedStringError, FixedStringView, FloatFormat, Rounding};
+
+fn format_f32(value: f32, decimals: u8) -> FixedString<64> {
+    let mut fixed_string = FixedString::new();
+    fixed_string.push_f32_fixed(value, decimals).unwrap();
+    fixed_string
+}
+
+#[test]
+fn f32_fixed() {
+    assert_eq!("3.14", format_f32(core::f32::consts::PI, 2).as_str());
+    assert_eq!("3", format_f32(core::f32::consts::PI, 0).as_str());
+    assert_eq!("-2.50", format_f32(-2.5, 2).as_str());
+    assert_eq!("0.05", format_f32(0.05, 2).as_str());
+    assert_eq!("1.000", format_f32(0.9999, 3).as_str());
+    assert_eq!("100", format_f32(99.5, 0).as_str());
+    assert_eq!(
+        "340282346638528859811704183484516925440.0",
+        format_f32(f32::MAX, 1).as_str()
+    );
+}
+
+#[test]
+fn f32_special_values() {
+    assert_eq!("NaN", format_f32(f32::NAN, 2).as_str());
+    assert_eq!("inf", format_f32(f32::INFINITY, 2).as_str());
+    assert_eq!("-inf", format_f32(f32::NEG_INFINITY, 2).as_str());
+
+    let format = FloatFormat {
+        nan: "---",
+        infinity: "OVF",
+        ..FloatFormat::new(1)
+    };
+    let mut fixed_string = FixedString::<16>::new();
+    fixed_string.push_f32_formatted(f32::NAN, &format).unwrap();
+    fixed_string
+        .push_f32_formatted(f32::NEG_INFINITY, &format)
+        .unwrap();
+    assert_eq!("----OVF", fixed_string.as_str());
+}
+
+#[test]
+fn f32_truncate() {
+    let format = FloatFormat {
+        rounding: Rounding::Truncate,
+        ..FloatFormat::new(1)
+    };
+    let mut fixed_string = FixedString::<16>::new();
+    fixed_string.push_f32_formatted(2.99, &format).unwrap();
+    assert_eq!("2.9", fixed_string.as_str());
+}
+
+#[test]
+fn f32_errors() {
+    let mut fixed_string = FixedString::<4>::new();
+    assert_eq!(
+        Err(FixedStringError::FormatError),
+        fixed_string.push_f32_fixed(1.0, 10)
+    );
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 5,
+            available: 4
+        }),
+        fixed_string.push_f32_fixed(-1.25, 2)
+    );
+    assert!(fixed_string.push_f32_fixed(f32::NEG_INFINITY, 2).is_ok());
+    assert!(fixed_string.push_f32_fixed(f32::NEG_INFINITY, 2).is_err());
+    assert_eq!("-inf", fixed_string.as_str());
+}
+
+#[test]
+fn uint_padded() {
+    let mut fixed_string = FixedString::<64>::new();
+    fixed_string.push_uint_padded(7u8, 4, 10).unwrap();
+    fixed_string.push(" 0x").unwrap();
+    fixed_string.push_uint_padded(0xffu16, 4, 16).unwrap();
+    fixed_string.push(" ").unwrap();
+    fixed_string.push_uint_padded(5u32, 8, 2).unwrap();
+    fixed_string.push(" ").unwrap();
+    fixed_string.push_uint_padded(123456u32, 2, 10).unwrap();
+    fixed_string.push(" ").unwrap();
+    fixed_string.push_uint_padded(u128::MAX, 0, 36).unwrap();
+    assert_eq!(
+        "0007 0x00FF 00000101 123456 F5LXX1ZZ5PNORYNQGLHZMSP33",
+        fixed_string.as_str()
+    );
+
+    assert_eq!(
+        Err(FixedStringError::FormatError),
+        fixed_string.push_uint_padded(1u8, 1, 37)
+    );
+    let mut short = FixedString::<4>::new();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 5,
+            available: 4
+        }),
+        short.push_uint_padded(42u8, 5, 10)
+    );
+    assert_eq!(0, short.length());
+}
+
+#[test]
+fn fixed_point() {
+    let format = |raw: i32, frac_bits: u8, decimals: u8| {
+        let mut fixed_string = FixedString::<32>::new();
+        fixed_string
+            .push_fixed_point(raw, frac_bits, decimals)
+            .unwrap();
+        fixed_string
+    };
+
+    assert_eq!("1.50", format(0x0001_8000, 16, 2).as_str());
+    assert_eq!("-1.25", format(-0x0001_4000, 16, 2).as_str());
+    assert_eq!("21.6", format(346, 4, 1).as_str());
+    assert_eq!("22", format(0x0000_0158 + 8, 4, 0).as_str());
+    assert_eq!("1.000", format(0xffff, 16, 3).as_str());
+    assert_eq!("32767.9999847", format(i32::MAX, 16, 7).as_str());
+    assert_eq!("-32768.000", format(i32::MIN, 16, 3).as_str());
+    assert_eq!("-0.5", format(i32::MIN, 32, 1).as_str());
+    assert_eq!("42", format(42, 0, 0).as_str());
+
+    assert_eq!(
+        Err(FixedStringError::FormatError),
+        FixedString::<32>::new().push_fixed_point(1, 33, 2)
+    );
+}
@@ -0,0 +1,39 @@
+use core::fmt::Write;
+
+use crate::{FixedStrMut, FixedString, FixedStringError, FixedStringView, OverflowStatsWriter};
+
+#[test]
+fn counts_overflow() {
+    let mut writer = OverflowStatsWriter::new(FixedString::<8>::new());
+    writer.push("temp=").unwrap();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 6,
+            available: 3
+        }),
+        writer.push("grüß")
+    );
+    assert_eq!("temp=gr", writer.get_ref().as_str());
+    assert_eq!(4, writer.dropped_bytes());
+    assert_eq!(1, writer.failed_writes());
+
+    let unit = "x";
+    write!(writer, "{}{}", 1, unit).unwrap();
+    writer.push("").unwrap();
+    assert_eq!("temp=gr1", writer.get_ref().as_str());
+    assert_eq!(5, writer.dropped_bytes());
+    assert_eq!(2, writer.failed_writes());
+
+    writer.reset_stats();
+    assert_eq!(0, writer.dropped_bytes());
+    assert_eq!("temp=gr1", writer.into_inner().as_str());
+}
+
+#[test]
+fn wraps_fixed_str_mut() {
+    let mut buffer = [0; 4];
+    let mut writer = OverflowStatsWriter::new(FixedStrMut::new(&mut buffer));
+    write!(writer, "{}", 123456).unwrap();
+    assert_eq!("1234", writer.get_ref().as_str());
+    assert_eq!(2, writer.dropped_bytes());
+}
@@ -0,0 +1,49 @@
+use crate::{FixedStrMut, FixedString, FixedStringError, FixedStringRef, FixedStringView};
+
+#[test]
+fn parse() {
+    let fixed_string = FixedString::<8>::new_with("-42").unwrap();
+    assert_eq!(Ok(-42), fixed_string.parse::<i32>());
+    assert_eq!(Ok(-42.0), fixed_string.parse::<f32>());
+    assert_eq!(
+        Err(FixedStringError::ParseError),
+        fixed_string.parse::<u8>()
+    );
+}
+
+#[test]
+fn parse_radix() {
+    let fixed_string = FixedString::<8>::new_with("ff").unwrap();
+    assert_eq!(Ok(255), fixed_string.parse_radix::<u32>(16));
+    assert_eq!(
+        Ok(-1),
+        FixedString::<8>::new_with("-1")
+            .unwrap()
+            .parse_radix::<i8>(2)
+    );
+    assert_eq!(
+        Err(FixedStringError::ParseError),
+        fixed_string.parse_radix::<u32>(10)
+    );
+}
+
+#[test]
+fn parse_radix_out_of_range() {
+    let fixed_string = FixedString::<8>::new_with("1").unwrap();
+    for radix in [0, 1, 37] {
+        assert_eq!(
+            Err(FixedStringError::ParseError),
+            fixed_string.parse_radix::<u32>(radix)
+        );
+    }
+}
+
+#[test]
+fn parse_str_mut() {
+    let mut buffer = [0; 8];
+    let mut fixed_str_mut = FixedStrMut::new(&mut buffer);
+    fixed_str_mut.push("1234").unwrap();
+
+    assert_eq!(Ok(1234), fixed_str_mut.parse::<u16>());
+    assert_eq!(Ok(0x1234), fixed_str_mut.parse_radix::<u16>(16));
+}
@@ -0,0 +1,93 @@
+use crate::{FixedStr, FixedString, FixedStringSearch, FixedStringView};
+
+#[test]
+fn match_indices() {
+    let fixed_string = FixedString::<32>::new_with("a=1;b=2;;c=3").unwrap();
+    assert!(
+        fixed_string
+            .match_indices(';')
+            .eq([(3, ";"), (7, ";"), (8, ";")])
+    );
+    assert!(fixed_string.match_indices(";;").eq([(7, ";;")]));
+    assert_eq!(3, fixed_string.matches('=').count());
+    assert_eq!(None, fixed_string.matches("x").next());
+}
+
+#[test]
+fn non_overlapping() {
+    let fixed_str = FixedStr::new(b"aaaa").unwrap();
+    assert!(fixed_str.match_indices("aa").eq([(0, "aa"), (2, "aa")]));
+}
+
+#[test]
+fn multibyte_and_empty() {
+    let fixed_string = FixedString::<16>::new_with("ö-ä-ö").unwrap();
+    assert!(fixed_string.match_indices('ö').eq([(0, "ö"), (6, "ö")]));
+    assert!(
+        fixed_string
+            .match_indices("")
+            .map(|(index, _)| index)
+            .eq([0, 2, 3, 5, 6, 8])
+    );
+}
+
+#[test]
+fn find() {
+    let fixed_string = FixedString::<16>::new_with("key = välue").unwrap();
+    assert_eq!(Some(4), fixed_string.find('='));
+    assert_eq!(Some(6), fixed_string.find("vä"));
+    assert_eq!(
+        Some(7),
+        fixed_string.find(|character: char| !character.is_ascii())
+    );
+    assert_eq!(None, fixed_string.find(char::is_numeric));
+}
+
+#[test]
+fn split() {
+    let fixed_string = FixedString::<32>::new_with("a,b,,c").unwrap();
+    assert!(fixed_string.split(',').eq(["a", "b", "", "c"]));
+    assert!(fixed_string.split(",,").eq(["a,b", "c"]));
+    assert!(fixed_string.split(';').eq(["a,b,,c"]));
+
+    let fixed_string = FixedString::<32>::new_with("1 2\t3").unwrap();
+    assert!(fixed_string.split(char::is_whitespace).eq(["1", "2", "3"]));
+    assert!(fixed_string.split("").eq(["1", " ", "2", "\t", "3"]));
+    assert!(FixedString::<4>::new().split(',').eq([""]));
+}
+
+#[test]
+fn trim_and_strip() {
+    let fixed_string = FixedString::<32>::new_with("--ab--cd--").unwrap();
+    assert_eq!("ab--cd", fixed_string.trim_matches('-'));
+    assert_eq!("ab--cd--", fixed_string.trim_start_matches("--"));
+    assert_eq!("--ab--cd", fixed_string.trim_end_matches(|c| c == '-'));
+    assert_eq!("--ab--cd--", fixed_string.trim_matches(""));
+
+    assert_eq!(Some("-ab--cd--"), fixed_string.strip_prefix('-'));
+    assert_eq!(Some("--ab--cd"), fixed_string.strip_suffix("--"));
+    assert_eq!(None, fixed_string.strip_prefix(char::is_alphabetic));
+    assert_eq!(
+        Some("2.0"),
+        FixedString::<8>::new_with("v2.0")
+            .unwrap()
+            .strip_prefix('v')
+    );
+}
+
+#[test]
+fn through_dyn() {
+    fn first_field(view: &dyn FixedStringView) -> Option<&str> {
+        view.find(";")?;
+        view.split(';').next()
+    }
+
+    let fixed_string = FixedString::<16>::new_with("a=1;b=2").unwrap();
+    let view: &dyn FixedStringView = &fixed_string;
+    assert_eq!(Some(3), view.find(";"));
+    assert_eq!(Some(1), view.find_pattern('='));
+    assert_eq!(Some("a=1"), first_field(view));
+    assert_eq!(2, view.matches('=').count());
+    assert_eq!(Some("=1;b=2"), view.strip_prefix('a'));
+    assert_eq!("=1;b=", view.trim_matches(char::is_alphanumeric));
+}
@@ -0,0 +1,64 @@
+use crate::{FixedString, FixedStringError, FixedStringView, PercentCharset};
+
+#[test]
+fn push_percent_encoded() {
+    let mut fixed_string = FixedString::<64>::new_with("?q=").unwrap();
+    fixed_string
+        .push_percent_encoded("a b&c=ö", PercentCharset::Unreserved)
+        .unwrap();
+    assert_eq!("?q=a%20b%26c%3D%C3%B6", fixed_string.as_str());
+
+    let mut fixed_string = FixedString::<64>::new_with("/").unwrap();
+    fixed_string
+        .push_percent_encoded("a:b@c/d e", PercentCharset::PathSegment)
+        .unwrap();
+    assert_eq!("/a:b@c%2Fd%20e", fixed_string.as_str());
+}
+
+#[test]
+fn push_percent_encoded_overflow() {
+    let mut fixed_string = FixedString::<4>::new();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 5,
+            available: 4
+        }),
+        fixed_string.push_percent_encoded("a b", PercentCharset::Unreserved)
+    );
+    assert_eq!("", fixed_string.as_str());
+}
+
+#[test]
+fn percent_decode_in_place() {
+    let mut fixed_string = FixedString::<64>::new_with("a%20b%26c%3d%C3%B6").unwrap();
+    fixed_string.percent_decode_in_place().unwrap();
+    assert_eq!("a b&c=ö", fixed_string.as_str());
+}
+
+#[test]
+fn percent_decode_long() {
+    let mut fixed_string = FixedString::<256>::new();
+    for _ in 0..30 {
+        fixed_string.push("x%C3%B6").unwrap();
+    }
+    fixed_string.percent_decode_in_place().unwrap();
+    assert_eq!(90, fixed_string.length());
+    assert!(fixed_string.as_str().starts_with("xöxö"));
+}
+
+#[test]
+fn percent_decode_invalid() {
+    let mut fixed_string = FixedString::<16>::new_with("50%").unwrap();
+    assert_eq!(
+        Err(FixedStringError::InvalidHex { index: 2 }),
+        fixed_string.percent_decode_in_place()
+    );
+    assert_eq!("50%", fixed_string.as_str());
+
+    let mut fixed_string = FixedString::<16>::new_with("a%C3").unwrap();
+    assert_eq!(
+        Err(FixedStringError::InvalidUtf8),
+        fixed_string.percent_decode_in_place()
+    );
+    assert_eq!("a%C3", fixed_string.as_str());
+}
@@ -0,0 +1,39 @@
+use core::fmt::Write;
+
+use crate::{FixedStringPool, FixedStringView};
+
+static POOL: FixedStringPool<32, 2> = FixedStringPool::new();
+
+#[test]
+fn acquire_and_release() {
+    let pool = FixedStringPool::<16, 2>::new();
+    assert_eq!(2, pool.available());
+
+    let mut first = pool.acquire().unwrap();
+    write!(first, "temp={}", 21).unwrap();
+    let second = pool.acquire().unwrap();
+    assert!(pool.acquire().is_none());
+    assert_eq!(0, pool.available());
+    assert_eq!("temp=21", first.as_str());
+
+    drop(first);
+    assert_eq!(1, pool.available());
+
+    let reused = pool.acquire().unwrap();
+    assert_eq!("", reused.as_str());
+    drop(second);
+    drop(reused);
+    assert_eq!(2, pool.available());
+}
+
+#[test]
+fn static_pool() {
+    fn assert_send<T: Send>(_: &T) {}
+
+    let mut message = POOL.acquire().unwrap();
+    message.push("from producer").unwrap();
+    assert_send(&message);
+
+    assert_eq!("from producer", message.as_str());
+    assert_eq!(1, POOL.available());
+}
@@ -0,0 +1,54 @@
+use postcard::experimental::max_size::MaxSize;
+
+use crate::{FixedString, FixedStringView};
+
+#[derive(MaxSize)]
+#[allow(dead_code)]
+struct Frame {
+    id: u8,
+    name: FixedString<200>,
+}
+
+#[test]
+fn max_size() {
+    assert_eq!(1, FixedString::<0>::POSTCARD_MAX_SIZE);
+    assert_eq!(13, FixedString::<12>::POSTCARD_MAX_SIZE);
+    assert_eq!(202, FixedString::<200>::POSTCARD_MAX_SIZE);
+    assert_eq!(203, Frame::POSTCARD_MAX_SIZE);
+}
+
+#[test]
+fn round_trip() {
+    let fixed_string = FixedString::<12>::new_with("Hello World!").unwrap();
+    let mut buffer = [0u8; FixedString::<12>::POSTCARD_MAX_SIZE];
+
+    let encoded = postcard::to_slice(&fixed_string, &mut buffer).unwrap();
+    assert_eq!(FixedString::<12>::POSTCARD_MAX_SIZE, encoded.len());
+
+    let decoded: FixedString<12> = postcard::from_bytes(encoded).unwrap();
+    assert_eq!("Hello World!", decoded.as_str());
+}
+
+#[test]
+fn deserialize_overflow() {
+    let fixed_string = FixedString::<12>::new_with("Hello World!").unwrap();
+    let mut buffer = [0u8; FixedString::<12>::POSTCARD_MAX_SIZE];
+
+    let encoded = postcard::to_slice(&fixed_string, &mut buffer).unwrap();
+    assert!(postcard::from_bytes::<FixedString<11>>(encoded).is_err());
+}
+
+#[test]
+fn deserialize_truncating() {
+    let fixed_string = FixedString::<16>::new_with("Hello Wörld!").unwrap();
+    let mut buffer = [0u8; FixedString::<16>::POSTCARD_MAX_SIZE];
+    let encoded = postcard::to_slice(&fixed_string, &mut buffer).unwrap();
+
+    let mut deserializer = postcard::Deserializer::from_bytes(encoded);
+    let truncated: FixedString<8> = crate::deserialize_truncating(&mut deserializer).unwrap();
+    assert_eq!("Hello W", truncated.as_str());
+
+    let mut deserializer = postcard::Deserializer::from_bytes(encoded);
+    let fitting: FixedString<16> = crate::deserialize_truncating(&mut deserializer).unwrap();
+    assert_eq!("Hello Wörld!", fitting.as_str());
+}
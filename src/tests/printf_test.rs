@@ -0,0 +1,68 @@
+use crate::{FixedString, FixedStringError, FixedStringView, PrintfArg};
+
+fn printf(format: &str, args: &[PrintfArg]) -> Result<FixedString<64>, FixedStringError> {
+    let mut fixed_string = FixedString::new();
+    fixed_string.push_printf(format, args)?;
+    Ok(fixed_string)
+}
+
+#[test]
+fn push_printf() {
+    assert_eq!(
+        "id=7 temp=-12 name=pump state=on 100%",
+        printf(
+            "id=%d temp=%i name=%s state=%s 100%%",
+            &[7u8.into(), (-12).into(), "pump".into(), "on".into()]
+        )
+        .unwrap()
+        .as_str()
+    );
+    assert_eq!(
+        "0a:FF|  -5|-0005|x  |  ö|42",
+        printf(
+            "%02x:%X|%4d|%05d|%-3c|%3s|%u",
+            &[
+                10u8.into(),
+                255u32.into(),
+                (-5).into(),
+                (-5).into(),
+                'x'.into(),
+                "ö".into(),
+                42.into()
+            ]
+        )
+        .unwrap()
+        .as_str()
+    );
+    assert_eq!(
+        "18446744073709551615 -9223372036854775808",
+        printf("%u %d", &[u64::MAX.into(), i64::MIN.into()])
+            .unwrap()
+            .as_str()
+    );
+}
+
+#[test]
+fn push_printf_errors() {
+    for (format, args) in [
+        ("%d", &[][..]),
+        ("%d", &["7".into()][..]),
+        ("%x", &[(-1).into()][..]),
+        ("%q", &[1.into()][..]),
+        ("%5", &[1.into()][..]),
+        ("none", &[1.into()][..]),
+    ] {
+        assert_eq!(
+            Err(FixedStringError::FormatError),
+            printf(format, args).map(|_| ())
+        );
+    }
+
+    let mut fixed_string = FixedString::<8>::new_with("> ").unwrap();
+    assert!(
+        fixed_string
+            .push_printf("%s", &["too long".into()])
+            .is_err()
+    );
+    assert_eq!("> ", fixed_string.as_str());
+}
@@ -0,0 +1,27 @@
+use crate::{FixedString, FixedStringError, FixedStringView, QueryPairs};
+
+#[test]
+fn query_pairs() {
+    let query = FixedString::<64>::new_with("a=1&&b=two&flag&c=x=y").unwrap();
+    let mut pairs = query.query_pairs();
+
+    assert_eq!(Some(("a", "1")), pairs.next());
+    assert_eq!(Some(("b", "two")), pairs.next());
+    assert_eq!(Some(("flag", "")), pairs.next());
+    assert_eq!(Some(("c", "x=y")), pairs.next());
+    assert_eq!(None, pairs.next());
+
+    assert_eq!(0, QueryPairs::new("").count());
+}
+
+#[test]
+fn from_form_encoded() {
+    let (_, value) = QueryPairs::new("name=J%C3%B6rgen+B%26B").next().unwrap();
+    let decoded = FixedString::<32>::from_form_encoded(value).unwrap();
+    assert_eq!("Jörgen B&B", decoded.as_str());
+
+    assert_eq!(
+        Err(FixedStringError::InvalidHex { index: 1 }),
+        FixedString::<16>::from_form_encoded("a%zz")
+    );
+}
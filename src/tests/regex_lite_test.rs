@@ -0,0 +1,73 @@
+use crate::{FixedString, FixedStringError, Regex};
+
+fn is_match(pattern: &str, text: &str) -> bool {
+    Regex::new(pattern).unwrap().is_match(text)
+}
+
+#[test]
+fn literals_and_anchors() {
+    assert!(is_match("abc", "xxabcxx"));
+    assert!(is_match("^abc", "abcxx"));
+    assert!(!is_match("^abc", "xabc"));
+    assert!(is_match("abc$", "xxabc"));
+    assert!(!is_match("abc$", "abcx"));
+    assert!(is_match("^$", ""));
+    assert!(!is_match("^$", "a"));
+    assert!(is_match("a.c", "abc"));
+    assert!(is_match(r"1\.5", "v1.5"));
+    assert!(!is_match(r"1\.5", "v125"));
+}
+
+#[test]
+fn quantifiers() {
+    assert!(is_match("^ab*c$", "ac"));
+    assert!(is_match("^ab*c$", "abbbc"));
+    assert!(is_match("^ab+c$", "abc"));
+    assert!(!is_match("^ab+c$", "ac"));
+    assert!(is_match("^colou?r$", "color"));
+    assert!(is_match("^colou?r$", "colour"));
+    assert!(!is_match("^colou?r$", "colouur"));
+    assert!(is_match("^a*a*a*b$", "aaaaaaaaaaaaaaaaaaab"));
+}
+
+#[test]
+fn classes() {
+    let ip = Regex::new(r"^\d+\.\d+\.\d+\.\d+$").unwrap();
+    assert!(ip.is_match("192.168.1.10"));
+    assert!(!ip.is_match("192.168.1"));
+
+    let hex = Regex::new("^0x[0-9a-fA-F]+$").unwrap();
+    assert!(hex.is_match("0xBEef"));
+    assert!(!hex.is_match("0xbeeg"));
+
+    assert!(is_match(r"^[^,]+$", "no commas"));
+    assert!(!is_match(r"^[^,]+$", "a,b"));
+    assert!(is_match(r"^[\w\-]+$", "node_1-a"));
+    assert!(is_match(r"^\w+\s\w+$", "grüße welt"));
+    assert!(is_match(r"^[\]x]$", "]"));
+}
+
+#[test]
+fn matches_regex() {
+    let name = Regex::new("^[a-z][a-z0-9_]*$").unwrap();
+    assert!(
+        FixedString::<16>::new_with("sensor_1")
+            .unwrap()
+            .matches_regex(&name)
+    );
+    assert!(
+        !FixedString::<16>::new_with("1sensor")
+            .unwrap()
+            .matches_regex(&name)
+    );
+}
+
+#[test]
+fn invalid_patterns() {
+    for pattern in ["*a", "a**", "[abc", "[]", "a\\", "^+"] {
+        assert_eq!(
+            Err(FixedStringError::ParseError),
+            Regex::new(pattern).map(|_| ())
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn resize() {
+    let mut fixed_string = FixedString::<16>::new_with("name").unwrap();
+    fixed_string.resize(8, ' ').unwrap();
+    assert_eq!("name    ", fixed_string.as_str());
+    fixed_string.resize(12, 'ö').unwrap();
+    assert_eq!("name    öö", fixed_string.as_str());
+    fixed_string.resize(3, ' ').unwrap();
+    assert_eq!("nam", fixed_string.as_str());
+
+    assert_eq!(
+        Err(FixedStringError::InvalidIndex {
+            index: 6,
+            length: 3
+        }),
+        fixed_string.resize(6, 'ö')
+    );
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 14,
+            available: 13
+        }),
+        fixed_string.resize(17, ' ')
+    );
+    assert_eq!("nam", fixed_string.as_str());
+
+    let mut fixed_string = FixedString::<8>::new_with("aö").unwrap();
+    assert_eq!(
+        Err(FixedStringError::InvalidIndex {
+            index: 2,
+            length: 3
+        }),
+        fixed_string.resize(2, ' ')
+    );
+}
+
+#[test]
+fn fill() {
+    let mut fixed_string = FixedString::<8>::new_with("old").unwrap();
+    fixed_string.fill(' ');
+    assert_eq!("        ", fixed_string.as_str());
+    fixed_string.fill('€');
+    assert_eq!("€€", fixed_string.as_str());
+}
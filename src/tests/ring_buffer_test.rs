@@ -0,0 +1,47 @@
+use crate::{FixedStringError, FixedStringRingBuffer};
+
+#[test]
+fn push_overwrite() {
+    let mut log = FixedStringRingBuffer::<16, 3>::new();
+    assert!(log.is_empty());
+
+    for line in ["boot", "init", "run"] {
+        log.push_overwrite(line).unwrap();
+    }
+    assert!(log.is_full());
+
+    log.push_overwrite_fmt(format_args!("fault {:#x}", 0xdead))
+        .unwrap();
+    assert_eq!(3, log.len());
+    assert_eq!(Some("init"), log.get(0));
+    assert_eq!(None, log.get(3));
+
+    let mut lines = log.iter();
+    assert_eq!(3, lines.len());
+    assert_eq!(Some("init"), lines.next());
+    assert_eq!(Some("fault 0xdead"), lines.next_back());
+    assert_eq!(Some("run"), lines.next());
+    assert_eq!(None, lines.next());
+}
+
+#[test]
+fn entry_overflow() {
+    let mut log = FixedStringRingBuffer::<4, 2>::new();
+    log.push_overwrite("ok").unwrap();
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 5,
+            available: 4
+        }),
+        log.push_overwrite("error")
+    );
+    assert_eq!(
+        Err(FixedStringError::FormatError),
+        log.push_overwrite_fmt(format_args!("{}", 12345))
+    );
+    assert!(log.iter().eq(["ok"]));
+
+    log.clear();
+    assert_eq!(0, log.iter().count());
+}
@@ -0,0 +1,54 @@
+use core::mem::MaybeUninit;
+
+use rkyv::{
+    api::low::{access, from_bytes, to_bytes_in_with_alloc},
+    rancor::Failure,
+    ser::{allocator::SubAllocator, writer::Buffer},
+    util::Align,
+};
+
+use crate::{ArchivedFixedString, FixedString};
+
+#[test]
+fn access_archived() {
+    let fixed_string = FixedString::<16>::new_with("Hello World!").unwrap();
+    let mut output = Align([MaybeUninit::<u8>::uninit(); 64]);
+    let mut alloc = [MaybeUninit::<u8>::uninit(); 64];
+
+    let bytes = to_bytes_in_with_alloc::<_, _, Failure>(
+        &fixed_string,
+        Buffer::from(&mut *output),
+        SubAllocator::new(&mut alloc),
+    )
+    .unwrap();
+
+    let archived = access::<ArchivedFixedString<16>, Failure>(&bytes).unwrap();
+    assert_eq!("Hello World!", archived.as_str());
+    assert_eq!(12, archived.length());
+    assert_eq!(16, archived.capacity());
+    assert!(*archived == fixed_string);
+}
+
+#[test]
+fn deserialize() {
+    let fixed_string = FixedString::<16>::new_with("Hello World!").unwrap();
+    let mut output = Align([MaybeUninit::<u8>::uninit(); 64]);
+    let mut alloc = [MaybeUninit::<u8>::uninit(); 64];
+
+    let bytes = to_bytes_in_with_alloc::<_, _, Failure>(
+        &fixed_string,
+        Buffer::from(&mut *output),
+        SubAllocator::new(&mut alloc),
+    )
+    .unwrap();
+
+    let deserialized = from_bytes::<FixedString<16>, Failure>(&bytes).unwrap();
+    assert_eq!(fixed_string, deserialized);
+}
+
+#[test]
+fn access_invalid_length() {
+    let mut bytes = Align([0u8; 8]);
+    bytes[0] = 5;
+    assert!(access::<ArchivedFixedString<4>, Failure>(&*bytes).is_err());
+}
@@ -0,0 +1,33 @@
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn collapse_whitespace() {
+    let mut fixed_string = FixedString::<32>::new_with(" \t pump \n\n 2\u{3000}ö  ").unwrap();
+    fixed_string.collapse_whitespace();
+    assert_eq!("pump 2 ö", fixed_string.as_str());
+
+    let mut fixed_string = FixedString::<8>::new_with(" \r\n ").unwrap();
+    fixed_string.collapse_whitespace();
+    assert_eq!("", fixed_string.as_str());
+}
+
+#[test]
+fn sanitize_control_chars() {
+    let mut fixed_string = FixedString::<16>::new_with("a\tb\u{7f}\u{85}ö\n").unwrap();
+    fixed_string.sanitize_control_chars('?').unwrap();
+    assert_eq!("a?b??ö?", fixed_string.as_str());
+
+    let mut fixed_string = FixedString::<16>::new_with("a\tb\u{85}ö").unwrap();
+    fixed_string.sanitize_control_chars('\u{fffd}').unwrap();
+    assert_eq!("a\u{fffd}b\u{fffd}ö", fixed_string.as_str());
+
+    let mut fixed_string = FixedString::<8, crate::Utf8, u8>::new_with("\0\0\0").unwrap();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 6,
+            available: 5
+        }),
+        fixed_string.sanitize_control_chars('\u{fffd}')
+    );
+    assert_eq!("\0\0\0", fixed_string.as_str());
+}
@@ -0,0 +1,55 @@
+use crate::{FixedStr, FixedString, FixedStringError, FixedStringView, ScanValue};
+
+#[test]
+fn scan() {
+    let fixed_string =
+        FixedString::<64>::new_with("V=3300,T=-12,ID=0x1F name=pump-1 ok 100%").unwrap();
+    let mut values = [ScanValue::Uint(0); 6];
+    assert_eq!(
+        Ok(6),
+        fixed_string.scan("V=%u,T=%d,ID=0x%x name=%s %s %c00%%", &mut values)
+    );
+    assert_eq!(
+        [
+            ScanValue::Uint(3300),
+            ScanValue::Int(-12),
+            ScanValue::Uint(0x1f),
+            ScanValue::Str("pump-1"),
+            ScanValue::Str("ok"),
+            ScanValue::Char('1'),
+        ],
+        values
+    );
+
+    let fixed_str = FixedStr::new(b"$GPGGA,rest,of,it").unwrap();
+    assert_eq!(Ok(2), fixed_str.scan("$%s,%s", &mut values));
+    assert_eq!(
+        [ScanValue::Str("GPGGA"), ScanValue::Str("rest,of,it")],
+        values[..2]
+    );
+}
+
+#[test]
+fn scan_errors() {
+    let fixed_string = FixedString::<32>::new_with("V=12,T=x").unwrap();
+    let mut values = [ScanValue::Int(0); 2];
+    for format in [
+        "V=%u,T=%d",
+        "V=%u",
+        "V=%u,T=%s,",
+        "W=%u,T=%s",
+        "V=%q,T=%s",
+        "V=%u,T=%c%c%c",
+    ] {
+        assert_eq!(
+            Err(FixedStringError::ParseError),
+            fixed_string.scan(format, &mut values)
+        );
+    }
+    assert_eq!(
+        Err(FixedStringError::ParseError),
+        FixedString::<32>::new_with("99999999999999999999")
+            .unwrap()
+            .scan("%u", &mut values)
+    );
+}
@@ -0,0 +1,82 @@
+use crate::{FixedStr, FixedString, FixedStringView};
+
+#[test]
+fn find_ignore_ascii_case() {
+    let header = FixedString::<64>::new_with("Host: x\r\ncontent-LENGTH: 12\r\n").unwrap();
+    assert_eq!(Some(9), header.find_ignore_ascii_case("Content-Length"));
+    assert_eq!(Some(0), header.find_ignore_ascii_case(""));
+    assert_eq!(None, header.find_ignore_ascii_case("Content-Type"));
+
+    let fixed_str = FixedStr::new("Grüße GRÜSSE".as_bytes()).unwrap();
+    assert_eq!(
+        Some(8),
+        FixedStringView::find_ignore_ascii_case(&fixed_str, "grÜ")
+    );
+    assert_eq!(
+        None,
+        FixedStringView::find_ignore_ascii_case(&fixed_str, "GRÜßE X")
+    );
+}
+
+#[test]
+fn counts() {
+    let fixed_string =
+        FixedString::<64>::new_with("Hällo wörld\r\n  two\u{a0}words\n\nlast\n").unwrap();
+    assert_eq!(
+        2,
+        fixed_string.count_char('ö') + fixed_string.count_char('ä')
+    );
+    assert_eq!(4, fixed_string.count_char('\n'));
+    assert_eq!(4, fixed_string.count_lines());
+    assert_eq!(5, fixed_string.count_words());
+
+    let empty = FixedString::<4>::new();
+    assert_eq!(0, empty.count_lines());
+    assert_eq!(0, empty.count_words());
+}
+
+#[test]
+fn char_boundaries() {
+    let fixed_string = FixedString::<16>::new_with("aö€").unwrap();
+    assert!(fixed_string.is_char_boundary(1));
+    assert!(!fixed_string.is_char_boundary(2));
+    assert!(fixed_string.is_char_boundary(6));
+    assert!(!fixed_string.is_char_boundary(7));
+
+    assert_eq!(3, fixed_string.floor_char_boundary(5));
+    assert_eq!(6, fixed_string.ceil_char_boundary(4));
+    assert_eq!(6, fixed_string.floor_char_boundary(100));
+    assert_eq!(6, fixed_string.ceil_char_boundary(100));
+    assert_eq!(1, fixed_string.ceil_char_boundary(1));
+}
+
+#[test]
+fn char_count() {
+    let fixed_string = FixedString::<16>::new_with("aö€😀").unwrap();
+    assert_eq!(10, fixed_string.length());
+    assert_eq!(4, fixed_string.char_count());
+    assert_eq!(4, fixed_string.get_ref().char_count());
+
+    let wide_string = crate::FixedWideString::<8>::new_with("aö€😀").unwrap();
+    assert_eq!(5, wide_string.length());
+    assert_eq!(4, wide_string.char_count());
+}
+
+#[test]
+fn streaming_comparison() {
+    let expected = FixedString::<8>::new_with("OK\r\n").unwrap();
+    assert!(expected.starts_with_chunks(&[]));
+    assert!(expected.starts_with_chunks(&[b"O", b"", b"K\r"]));
+    assert!(expected.starts_with_chunks(&[b"OK", b"\r\n"]));
+    assert!(!expected.starts_with_chunks(&[b"OK", b"\r\n", b"x"]));
+    assert!(!expected.starts_with_chunks(&[b"ER"]));
+
+    assert!(expected.eq_bytes_iter(b"OK\r\n".iter().copied()));
+    assert!(expected.eq_bytes_iter([b'O', b'K', b'\r', b'\n']));
+    assert!(!expected.eq_bytes_iter(b"OK\r".iter().copied()));
+    assert!(!expected.eq_bytes_iter(b"OK\r\n\n".iter().copied()));
+
+    let fixed_str = FixedStr::new(b"ERROR").unwrap();
+    assert!(fixed_str.starts_with_chunks(&[b"ERR"]));
+    assert!(fixed_str.eq_bytes_iter(*b"ERROR"));
+}
@@ -0,0 +1,41 @@
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn split_off() {
+    let mut frame = FixedString::<32>::new_with("HDR:7|payload ö").unwrap();
+    let payload = frame.split_off::<16>(6).unwrap();
+    assert_eq!("HDR:7|", frame.as_str());
+    assert_eq!("payload ö", payload.as_str());
+
+    let mut same = FixedString::<8>::new_with("ab").unwrap();
+    let tail: FixedString<8> = same.split_off(2).unwrap();
+    assert_eq!("", tail.as_str());
+    assert_eq!("ab", same.as_str());
+}
+
+#[test]
+fn split_off_errors() {
+    let mut fixed_string = FixedString::<16>::new_with("aö-tail").unwrap();
+    assert_eq!(
+        Err(FixedStringError::InvalidIndex {
+            index: 2,
+            length: 8
+        }),
+        fixed_string.split_off::<16>(2)
+    );
+    assert_eq!(
+        Err(FixedStringError::InvalidIndex {
+            index: 9,
+            length: 8
+        }),
+        fixed_string.split_off::<16>(9)
+    );
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 5,
+            available: 4
+        }),
+        fixed_string.split_off::<4>(3)
+    );
+    assert_eq!("aö-tail", fixed_string.as_str());
+}
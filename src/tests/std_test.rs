@@ -0,0 +1,39 @@
+use std::{error::Error, io::Write};
+
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn io_write() {
+    let mut fixed_string = FixedString::<12>::new();
+    let name = "World!";
+    write!(fixed_string, "Hello {}", name).unwrap();
+    assert_eq!("Hello World!", fixed_string.as_str());
+}
+
+#[test]
+fn io_write_partial() {
+    let mut fixed_string = FixedString::<5>::new();
+    assert_eq!(5, fixed_string.write("Hällo".as_bytes()).unwrap());
+    assert_eq!("Häll", fixed_string.as_str());
+
+    assert_eq!(0, fixed_string.write("ö".as_bytes()).unwrap());
+    assert!(fixed_string.write_all(b"World!").is_err());
+}
+
+#[test]
+fn io_write_invalid_utf8() {
+    let mut fixed_string = FixedString::<12>::new();
+    assert!(fixed_string.write(&[0xc3, 0x28]).is_err());
+}
+
+#[test]
+fn error() {
+    let error: &dyn Error = &FixedStringError::Overflow {
+        requested: 12,
+        available: 11,
+    };
+    assert_eq!(
+        "string capacity exceeded, 12 bytes requested but only 11 available",
+        std::format!("{}", error)
+    );
+}
@@ -0,0 +1,56 @@
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn render_template() {
+    let mut fixed_string = FixedString::<32>::new();
+    fixed_string
+        .render_template("Device {id} {state}, {{{id}}}", |key, out| match key {
+            "id" => out.push_uint_padded(7u8, 3, 10),
+            "state" => out.push("online"),
+            _ => Err(FixedStringError::FormatError),
+        })
+        .unwrap();
+    assert_eq!("Device 007 online, {007}", fixed_string.as_str());
+}
+
+#[test]
+fn render_template_errors() {
+    let mut fixed_string = FixedString::<16>::new_with("> ").unwrap();
+    for template in ["{id", "id}", "{a{b}", "{x}"] {
+        assert_eq!(
+            Err(FixedStringError::FormatError),
+            fixed_string.render_template(template, |key, out| match key {
+                "id" => out.push("1"),
+                _ => Err(FixedStringError::FormatError),
+            })
+        );
+    }
+    assert!(
+        fixed_string
+            .render_template("{id} is too long to fit", |_, out| out.push("1"))
+            .is_err()
+    );
+    assert_eq!("> ", fixed_string.as_str());
+}
+
+#[test]
+fn render_template_keeps_content() {
+    let mut fixed_string = FixedString::<8>::new_with("é").unwrap();
+    assert_eq!(
+        Err(FixedStringError::FormatError),
+        fixed_string.render_template("{x}", |_, out| {
+            out.clear();
+            out.push("a")?;
+            Err(FixedStringError::FormatError)
+        })
+    );
+    assert_eq!("é", fixed_string.as_str());
+
+    fixed_string
+        .render_template("{x}", |_, out| {
+            out.clear();
+            out.push("a")
+        })
+        .unwrap();
+    assert_eq!("éa", fixed_string.as_str());
+}
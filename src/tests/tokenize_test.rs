@@ -0,0 +1,38 @@
+use crate::{FixedString, FixedStringError, FixedStringView};
+
+#[test]
+fn tokenize() {
+    let command = FixedString::<32>::new_with("  set led\t 3  on ").unwrap();
+    let (args, count) = command.tokenize::<6>().unwrap();
+
+    assert_eq!(4, count);
+    assert_eq!(["set", "led", "3", "on"], args[..count]);
+
+    let (_, count) = FixedString::<4>::new_with("   ")
+        .unwrap()
+        .tokenize::<2>()
+        .unwrap();
+    assert_eq!(0, count);
+}
+
+#[test]
+fn tokenize_too_many() {
+    let command = FixedString::<32>::new_with("a b c d").unwrap();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 4,
+            available: 3
+        }),
+        command.tokenize::<3>()
+    );
+}
+
+#[test]
+fn tokenize_owned() {
+    let command = FixedString::<32>::new_with("get temperature").unwrap();
+    let (args, count) = command.tokenize_owned::<4, 12>().unwrap();
+
+    assert_eq!(2, count);
+    assert_eq!("temperature", args[1].as_str());
+    assert!(command.tokenize_owned::<4, 8>().is_err());
+}
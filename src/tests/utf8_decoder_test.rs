@@ -0,0 +1,52 @@
+use crate::{FixedString, FixedStringError, FixedStringRef, FixedStringView, Utf8Decoder};
+
+#[test]
+fn decode_chunks() {
+    let bytes = "temp=21°C €".as_bytes();
+    let mut decoder = Utf8Decoder::new();
+    let mut fixed_string = FixedString::<16>::new();
+
+    decoder.decode(&bytes[..8], &mut fixed_string).unwrap();
+    assert_eq!("temp=21", fixed_string.as_str());
+    assert!(decoder.is_pending());
+    decoder.decode(&bytes[8..13], &mut fixed_string).unwrap();
+    assert_eq!("temp=21°C ", fixed_string.as_str());
+    decoder.decode(&bytes[13..], &mut fixed_string).unwrap();
+    assert_eq!("temp=21°C €", fixed_string.as_str());
+    assert!(!decoder.is_pending());
+}
+
+#[test]
+fn invalid_and_incomplete() {
+    let mut decoder = Utf8Decoder::default();
+    let mut fixed_string = FixedString::<16>::new();
+    decoder
+        .decode(b"a\xffb\xc3c\xe2\x82", &mut fixed_string)
+        .unwrap();
+    assert_eq!("a\u{fffd}b\u{fffd}c", fixed_string.as_str());
+
+    decoder.finish(&mut fixed_string).unwrap();
+    assert_eq!("a\u{fffd}b\u{fffd}c\u{fffd}", fixed_string.as_str());
+    assert!(!decoder.is_pending());
+}
+
+#[test]
+fn overflow() {
+    let mut decoder = Utf8Decoder::new();
+    let mut fixed_string = FixedString::<4>::new_with("ab").unwrap();
+    decoder.decode(b"\xc3", &mut fixed_string).unwrap();
+
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 3,
+            available: 2
+        }),
+        decoder.decode(b"\xb6c", &mut fixed_string)
+    );
+    assert_eq!("ab", fixed_string.as_str());
+    assert!(decoder.is_pending());
+
+    fixed_string.truncate(1).unwrap();
+    decoder.decode(b"\xb6c", &mut fixed_string).unwrap();
+    assert_eq!("aöc", fixed_string.as_str());
+}
@@ -0,0 +1,43 @@
+use zerocopy::{FromBytes, IntoBytes};
+
+use crate::{FixedString, FixedStringError, FixedStringView, RawFixedString};
+
+#[test]
+fn to_bytes() {
+    let fixed_string = FixedString::<8>::new_with("Hello").unwrap();
+    let raw = RawFixedString::from(&fixed_string);
+
+    assert_eq!(
+        [5, 0, 0, 0, b'H', b'e', b'l', b'l', b'o', 0, 0, 0],
+        raw.as_bytes()
+    );
+}
+
+#[test]
+fn from_bytes() {
+    let bytes = [5, 0, 0, 0, b'H', b'e', b'l', b'l', b'o', 0, 0, 0];
+    let raw = RawFixedString::<8>::read_from_bytes(&bytes).unwrap();
+    let fixed_string = FixedString::try_from(&raw).unwrap();
+
+    assert_eq!("Hello", fixed_string.as_str());
+}
+
+#[test]
+fn from_invalid_bytes() {
+    let too_long = [9, 0, 0, 0, b'H', b'e', b'l', b'l', b'o', 0, 0, 0];
+    let raw = RawFixedString::<8>::read_from_bytes(&too_long).unwrap();
+    assert_eq!(
+        Err(FixedStringError::Overflow {
+            requested: 9,
+            available: 8
+        }),
+        FixedString::try_from(&raw)
+    );
+
+    let invalid_utf8 = [2, 0, 0, 0, 0xc3, 0x28, 0, 0, 0, 0, 0, 0];
+    let raw = RawFixedString::<8>::read_from_bytes(&invalid_utf8).unwrap();
+    assert_eq!(
+        Err(FixedStringError::InvalidUtf8),
+        FixedString::try_from(&raw)
+    );
+}
@@ -0,0 +1,52 @@
+use core::fmt::Write;
+
+use zeroize::Zeroize;
+
+use crate::{FixedString, FixedStringView, SecretFixedString};
+
+#[test]
+fn zeroize_fixed_string() {
+    let mut fixed_string = FixedString::<16>::new_with("Hello World!").unwrap();
+    fixed_string.zeroize();
+
+    assert_eq!(0, fixed_string.length());
+    assert_eq!(&[0; 16], fixed_string.raw());
+}
+
+#[test]
+fn secret_clear() {
+    let mut secret = SecretFixedString::<16>::new_with("hunter2").unwrap();
+    assert_eq!("hunter2", secret.as_str());
+
+    secret.clear();
+    assert_eq!("", secret.as_str());
+    assert_eq!(&[0; 16], secret.inner.raw());
+}
+
+#[test]
+fn secret_take() {
+    let mut secret = SecretFixedString::<16>::new();
+    write!(secret, "pin: {}", 1234).unwrap();
+
+    let taken = secret.take();
+    assert_eq!("pin: 1234", taken.as_str());
+    assert_eq!(0, secret.length());
+    assert_eq!(&[0; 16], secret.inner.raw());
+}
+
+#[test]
+fn secret_debug_redacted() {
+    let secret = SecretFixedString::<16>::new_with("hunter2").unwrap();
+    let mut output = FixedString::<32>::new();
+    write!(output, "{:?}", secret).unwrap();
+
+    assert_eq!("SecretFixedString<16>(***)", output.as_str());
+}
+
+#[test]
+fn secret_ct_eq() {
+    let secret = SecretFixedString::<16>::new_with("hunter2").unwrap();
+
+    assert!(secret.ct_eq(&SecretFixedString::new_with("hunter2").unwrap()));
+    assert!(!secret.ct_eq(&SecretFixedString::new_with("hunter3").unwrap()));
+}
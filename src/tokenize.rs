@@ -0,0 +1,40 @@
+use core::result::Result::{self, Err, Ok};
+
+use crate::{FixedString, FixedStringError, FixedStringView, LengthType, Utf8};
+
+impl<const N: usize, L: LengthType> FixedString<N, Utf8, L> {
+    /// Splits the content on whitespace into at most `MAX_ARGS` tokens,
+    /// returning them together with the number of tokens found
+    pub fn tokenize<const MAX_ARGS: usize>(
+        &self,
+    ) -> Result<([&str; MAX_ARGS], usize), FixedStringError> {
+        let mut tokens = [""; MAX_ARGS];
+        let mut count = 0;
+        for token in self.as_str().split_whitespace() {
+            let Some(slot) = tokens.get_mut(count) else {
+                return Err(FixedStringError::Overflow {
+                    requested: self.as_str().split_whitespace().count(),
+                    available: MAX_ARGS,
+                });
+            };
+            *slot = token;
+            count = count.saturating_add(1);
+        }
+
+        Ok((tokens, count))
+    }
+
+    /// Splits the content on whitespace into at most `MAX_ARGS` tokens copied
+    /// into `FixedString`s of capacity `M`
+    pub fn tokenize_owned<const MAX_ARGS: usize, const M: usize>(
+        &self,
+    ) -> Result<([FixedString<M>; MAX_ARGS], usize), FixedStringError> {
+        let (tokens, count) = self.tokenize::<MAX_ARGS>()?;
+        let mut owned = [FixedString::new(); MAX_ARGS];
+        for (token, owned) in tokens.iter().take(count).zip(owned.iter_mut()) {
+            owned.push(token)?;
+        }
+
+        Ok((owned, count))
+    }
+}
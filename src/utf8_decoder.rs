@@ -0,0 +1,110 @@
+use core::{
+    default::Default,
+    option::Option::{self, None, Some},
+    result::Result::{self, Err, Ok},
+    str,
+};
+
+use crate::{FixedString, FixedStringError, LengthType, Utf8};
+
+/// Decodes UTF-8 arriving in chunks, e.g. BLE notifications, where a
+/// character may be split between two chunks. Invalid UTF-8 is replaced with
+/// `U+FFFD`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Utf8Decoder {
+    /// The bytes of a character that isn't complete yet
+    partial: [u8; 4],
+    partial_length: usize,
+}
+
+impl Utf8Decoder {
+    /// Creates a new `Utf8Decoder` without pending bytes
+    pub const fn new() -> Self {
+        Self {
+            partial: [0; 4],
+            partial_length: 0,
+        }
+    }
+
+    /// Appends the characters completed by `chunk` to `out`, keeping the
+    /// bytes of a trailing incomplete character for the next chunk. Neither
+    /// `out` nor the decoder is changed if the characters don't fit.
+    pub fn decode<const N: usize, L: LengthType>(
+        &mut self,
+        chunk: &[u8],
+        out: &mut FixedString<N, Utf8, L>,
+    ) -> Result<(), FixedStringError> {
+        let decoder = *self;
+        let length = out.length();
+        let mut requested = 0usize;
+        let mut overflowed = false;
+
+        for &byte in chunk {
+            for character in self.push_byte(byte).into_iter().flatten() {
+                requested = requested.saturating_add(character.len_utf8());
+                // Keep counting after an overflow to report the full size
+                overflowed = overflowed || out.push_char(character).is_err();
+            }
+        }
+
+        if overflowed {
+            *self = decoder;
+            out.length = L::from_usize(length);
+            return Err(FixedStringError::Overflow {
+                requested,
+                available: N.saturating_sub(length),
+            });
+        }
+        Ok(())
+    }
+
+    /// Ends the input, appending `U+FFFD` to `out` for an incomplete
+    /// character at its end
+    pub fn finish<const N: usize, L: LengthType>(
+        &mut self,
+        out: &mut FixedString<N, Utf8, L>,
+    ) -> Result<(), FixedStringError> {
+        if self.partial_length > 0 {
+            out.push_char(char::REPLACEMENT_CHARACTER)?;
+            self.partial_length = 0;
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if bytes of an incomplete character are waiting for the
+    /// next chunk
+    pub fn is_pending(&self) -> bool {
+        self.partial_length > 0
+    }
+
+    /// Adds a byte, returning up to two characters it completed
+    fn push_byte(&mut self, byte: u8) -> [Option<char>; 2] {
+        let mut characters = [None; 2];
+        if self.partial_length > 0 && byte & 0xc0 != 0x80 {
+            characters[0] = Some(char::REPLACEMENT_CHARACTER);
+            self.partial_length = 0;
+        }
+
+        if let Some(partial) = self.partial.get_mut(self.partial_length) {
+            *partial = byte;
+        }
+        self.partial_length = self.partial_length.saturating_add(1);
+        let width = match self.partial[0] {
+            0x00..0x80 => 1,
+            0xc2..0xe0 => 2,
+            0xe0..0xf0 => 3,
+            0xf0..0xf5 => 4,
+            _ => 0,
+        };
+        if self.partial_length >= width {
+            characters[1] = Some(
+                str::from_utf8(self.partial.get(..self.partial_length).unwrap_or_default())
+                    .ok()
+                    .and_then(|character| character.chars().next())
+                    .unwrap_or(char::REPLACEMENT_CHARACTER),
+            );
+            self.partial_length = 0;
+        }
+        characters
+    }
+}
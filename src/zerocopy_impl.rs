@@ -0,0 +1,63 @@
+use core::{convert::From, convert::TryFrom, result::Result, str};
+
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned, little_endian::U32};
+
+use crate::{CHARACTER, CHARACTER_NONE, FixedString, FixedStringError};
+
+/// Raw representation of a `FixedString` with a well-defined layout, a little
+/// endian `u32` length followed by the full buffer. Can be copied directly to
+/// and from DMA descriptors, flash pages or other byte buffers.
+#[derive(Debug, Clone, Copy, FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+#[repr(C)]
+pub struct RawFixedString<const N: usize> {
+    length: U32,
+    buffer: [CHARACTER; N],
+}
+
+impl<const N: usize> RawFixedString<N> {
+    /// Returns the length stored in the raw representation
+    pub fn length(&self) -> usize {
+        self.length.get() as usize
+    }
+}
+
+/// Create a raw representation of a `FixedString`. Bytes past the length are
+/// zeroed.
+impl<const N: usize> From<&FixedString<N>> for RawFixedString<N> {
+    fn from(fixed_string: &FixedString<N>) -> Self {
+        let mut buffer = [CHARACTER_NONE; N];
+        if let Some(units) = buffer.get_mut(..fixed_string.length) {
+            units.copy_from_slice(fixed_string.as_units());
+        }
+
+        Self {
+            length: U32::new(fixed_string.length as u32),
+            buffer,
+        }
+    }
+}
+
+/// Create a `FixedString` from its raw representation, validating the length
+/// and content
+impl<const N: usize> TryFrom<&RawFixedString<N>> for FixedString<N> {
+    type Error = FixedStringError;
+
+    fn try_from(raw: &RawFixedString<N>) -> Result<Self, Self::Error> {
+        let length = raw.length();
+        let Some(units) = raw.buffer.get(..length) else {
+            return Err(FixedStringError::Overflow {
+                requested: length,
+                available: N,
+            });
+        };
+        if str::from_utf8(units).is_err() {
+            return Err(FixedStringError::InvalidUtf8);
+        }
+
+        let mut fixed_string = FixedString::new();
+        fixed_string.write_units(0, units)?;
+        fixed_string.length = length;
+
+        Ok(fixed_string)
+    }
+}
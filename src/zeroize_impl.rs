@@ -0,0 +1,121 @@
+use core::{
+    default::Default,
+    fmt,
+    ops::Drop,
+    result::Result::{self, Ok},
+    write,
+};
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{Encoding, FixedString, FixedStringError, FixedStringView, LengthType};
+
+impl<const N: usize, E: Encoding, L: LengthType> Zeroize for FixedString<N, E, L>
+where
+    E::Unit: Zeroize,
+    L: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.buffer.zeroize();
+        self.length.zeroize();
+    }
+}
+
+/// A `FixedString` for secrets such as passphrases and keys. The whole buffer
+/// is wiped when the string is cleared, taken or dropped, and the content is
+/// never printed by `Debug`.
+#[derive(Clone)]
+pub struct SecretFixedString<const N: usize> {
+    pub(crate) inner: FixedString<N>,
+}
+
+impl<const N: usize> SecretFixedString<N> {
+    /// Creates a new empty `SecretFixedString`.
+    pub const fn new() -> Self {
+        Self {
+            inner: FixedString::new(),
+        }
+    }
+
+    /// Creates a new `SecretFixedString` with an assigned value
+    pub fn new_with(string: &str) -> Result<Self, FixedStringError> {
+        let mut res = Self::new();
+        res.push(string)?;
+        Ok(res)
+    }
+
+    /// Returns a `&str` representation of the secret
+    pub fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns the current length
+    pub fn length(&self) -> usize {
+        self.inner.length
+    }
+
+    /// Returns the capacity
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the `SecretFixedString` is full.
+    pub fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+
+    /// Compares the secret with `other` in constant time, see
+    /// `FixedString::ct_eq`
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        self.inner.ct_eq(&other.inner)
+    }
+
+    /// Wipe the content of a `SecretFixedString`
+    pub fn clear(&mut self) {
+        self.inner.zeroize();
+    }
+
+    /// Take the content from a `SecretFixedString`, leaving a wiped
+    /// `SecretFixedString`
+    pub fn take(&mut self) -> Self {
+        let res = Self { inner: self.inner };
+        self.inner.zeroize();
+        res
+    }
+
+    /// Appends a string slice, if it fits
+    pub fn push(&mut self, string: &str) -> Result<(), FixedStringError> {
+        self.inner.push(string)
+    }
+
+    /// Appends a character, if it fits
+    pub fn push_char(&mut self, character: char) -> Result<(), FixedStringError> {
+        self.inner.push_char(character)
+    }
+}
+
+impl<const N: usize> Drop for SecretFixedString<N> {
+    fn drop(&mut self) {
+        self.inner.zeroize();
+    }
+}
+
+impl<const N: usize> ZeroizeOnDrop for SecretFixedString<N> {}
+
+impl<const N: usize> fmt::Debug for SecretFixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretFixedString<{}>(***)", N)
+    }
+}
+
+impl<const N: usize> fmt::Write for SecretFixedString<N> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push(string).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize> Default for SecretFixedString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}